@@ -0,0 +1,91 @@
+use std::{
+    fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use swc_global_esm::GlobalEsmConfig;
+
+use crate::{module_name, transform, Diagnostic, TransformCache, TransformOutput};
+
+/// Options of the batch transform.
+#[derive(Debug, Clone, Default)]
+pub struct BatchOptions {
+    /// Number of worker threads. Defaults to the available parallelism.
+    pub threads: Option<NonZeroUsize>,
+    /// Cache of the transform outputs.
+    pub cache: Option<TransformCache>,
+}
+
+/// Transform result of the file.
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    pub path: PathBuf,
+    pub module_name: String,
+    pub output: Result<TransformOutput, Vec<Diagnostic>>,
+}
+
+/// Transform the files concurrently with the shared config.
+///
+/// Results are returned in the order of the files. Files that can't be read are reported
+/// as diagnostics of their results.
+pub fn transform_batch(
+    files: &[PathBuf],
+    root: &Path,
+    config: &GlobalEsmConfig,
+    options: &BatchOptions,
+) -> Vec<BatchResult> {
+    let threads = options
+        .threads
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get)
+        .min(files.len().max(1));
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(files.len()));
+
+    thread::scope(|scope| {
+        (0..threads).for_each(|_| {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(path) = files.get(index) else {
+                    break;
+                };
+                let result = transform_entry(path, root, config, options.cache.as_ref());
+                results.lock().unwrap().push((index, result));
+            });
+        });
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Read and transform the file of the batch, using the cache if any.
+fn transform_entry(
+    path: &Path,
+    root: &Path,
+    config: &GlobalEsmConfig,
+    cache: Option<&TransformCache>,
+) -> BatchResult {
+    let module_name = module_name(path, root);
+    let output = fs::read_to_string(path)
+        .map_err(|err| vec![Diagnostic::new(path, err.to_string())])
+        .and_then(|source| match cache {
+            Some(cache) => cache
+                .transform(path, &module_name, source, config)
+                .map(TransformOutput::from),
+            None => transform(path, &module_name, source, config),
+        });
+
+    BatchResult {
+        path: path.to_path_buf(),
+        module_name,
+        output,
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use swc_core::{
+    common::{comments::SingleThreadedComments, sync::Lrc, Globals, SourceMap, DUMMY_SP, GLOBALS},
+    ecma::ast::{Module, Program},
+};
+use swc_global_esm::{generate_runtime, GlobalEsmConfig, Target, Wrap};
+
+use crate::{
+    transform::{emit, parse, transform_program},
+    Diagnostic, ModuleGraph, ModuleNode, TransformOutput,
+};
+
+/// File name of the runtime in the source map.
+const RUNTIME_FILE_NAME: &str = "global-esm/runtime.js";
+
+/// Bundle the entries and their dependencies into one script with a combined source map.
+///
+/// Modules are transformed in runtime mode, wrapped with an IIFE to isolate their scopes and
+/// concatenated in evaluation order after the runtime prelude (`generate_runtime` of the config).
+/// Imports resolved in the graph are requested by module id, and unresolved ones
+/// (eg. packages) must be registered before the bundle runs.
+///
+/// Modules that use top-level `await` can't be wrapped with an IIFE, so they are reported
+/// unless `async_mode` is set.
+pub fn bundle(
+    graph: &ModuleGraph,
+    entries: &[&str],
+    config: &GlobalEsmConfig,
+) -> Result<TransformOutput, Vec<Diagnostic>> {
+    let missing_entries = entries
+        .iter()
+        .filter(|entry| graph.module(entry).is_none())
+        .map(|entry| Diagnostic::new(entry, "entry module is not in the graph"))
+        .collect::<Vec<_>>();
+    if !missing_entries.is_empty() {
+        return Err(missing_entries);
+    }
+
+    let cm: Lrc<SourceMap> = Default::default();
+    let comments = SingleThreadedComments::default();
+
+    GLOBALS.set(&Globals::new(), || {
+        let runtime_path = Path::new(RUNTIME_FILE_NAME);
+        let mut body = parse(
+            &cm,
+            runtime_path,
+            RUNTIME_FILE_NAME,
+            generate_runtime(config),
+            Some(&comments),
+        )?
+        .body;
+
+        let mut diagnostics = Vec::new();
+        graph
+            .evaluation_order_from(entries.iter().copied())
+            .iter()
+            .filter_map(|id| graph.module(id))
+            .for_each(|node| {
+                let Some(source_file) = graph.source_file(node) else {
+                    return;
+                };
+                if node.info.has_top_level_await && !config.async_mode {
+                    diagnostics.push(Diagnostic::new(
+                        &node.path,
+                        "top-level `await` is not supported in the bundle without `asyncMode`",
+                    ));
+                    return;
+                }
+                match transform_program(
+                    &cm,
+                    &comments,
+                    &node.path,
+                    &node.id,
+                    source_file.src.to_string(),
+                    &module_config(node, config),
+                ) {
+                    Ok((program, _)) => body.extend(program.expect_module().body),
+                    Err(module_diagnostics) => diagnostics.extend(module_diagnostics),
+                }
+            });
+        if !diagnostics.is_empty() {
+            return Err(diagnostics);
+        }
+
+        let program = Program::Module(Module {
+            span: DUMMY_SP,
+            body,
+            shebang: None,
+        });
+        let path = Path::new(entries.first().copied().unwrap_or_default());
+        emit(&cm, &comments, path, &program)
+    })
+}
+
+/// Returns the config of the module in the bundle.
+///
+/// Resolved dependencies are requested by module id (`importPaths`).
+/// Modules are always wrapped with an IIFE since a block (`wrap: block`) leaks
+/// `var` and function declarations to the global scope of the bundle.
+fn module_config(node: &ModuleNode, config: &GlobalEsmConfig) -> GlobalEsmConfig {
+    let mut import_paths = config.import_paths.clone().unwrap_or_default();
+    node.dependencies.iter().for_each(|dep| {
+        if let Some(resolved) = &dep.resolved {
+            import_paths.insert(dep.specifier.clone(), resolved.clone());
+        }
+    });
+
+    GlobalEsmConfig {
+        runtime_module: true,
+        import_paths: Some(import_paths),
+        target: Target::Global,
+        wrap: Wrap::Iife,
+        ..config.clone()
+    }
+}
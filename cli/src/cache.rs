@@ -0,0 +1,132 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    hash::Hash,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use siphasher::sip128::{Hasher128, SipHasher13};
+use swc_global_esm::{GlobalEsmConfig, VERSION};
+
+use crate::{transform::transform_file, Diagnostic, TransformOutput};
+
+/// Cached transform output of the file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedTransform {
+    pub code: String,
+    /// Source map of the code (JSON).
+    pub map: String,
+    /// Module sources the module depends on, in source order.
+    pub dependencies: Vec<String>,
+}
+
+impl From<CachedTransform> for TransformOutput {
+    fn from(cached: CachedTransform) -> Self {
+        TransformOutput {
+            code: cached.code,
+            map: cached.map,
+        }
+    }
+}
+
+/// On-disk cache of the transform outputs.
+///
+/// Entries are keyed by the module name, the source, the config and the transform crate version,
+/// so changing any of them misses the cache (stale entries are left until `clear`).
+#[derive(Debug, Clone)]
+pub struct TransformCache {
+    dir: PathBuf,
+}
+
+impl TransformCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        TransformCache { dir: dir.into() }
+    }
+
+    /// Returns the cache key of the source (hex string).
+    pub fn key(module_name: &str, source: &str, config: &GlobalEsmConfig) -> String {
+        TransformCache::key_with_version(VERSION, module_name, source, config)
+    }
+
+    /// Same as `key`, but with the given transform crate version instead of the current one.
+    pub fn key_with_version(
+        version: &str,
+        module_name: &str,
+        source: &str,
+        config: &GlobalEsmConfig,
+    ) -> String {
+        // `import_paths` is sorted to serialize the config in the same order every time.
+        let mut config = config.clone();
+        let import_paths = config
+            .import_paths
+            .take()
+            .map(|import_paths| import_paths.into_iter().collect::<BTreeMap<_, _>>());
+        let config = serde_json::to_string(&(config, import_paths)).unwrap_or_default();
+
+        let mut hasher = SipHasher13::new();
+        version.hash(&mut hasher);
+        config.hash(&mut hasher);
+        module_name.hash(&mut hasher);
+        source.hash(&mut hasher);
+        format!("{:032x}", hasher.finish128().as_u128())
+    }
+
+    /// Returns the cached transform of the key.
+    ///
+    /// Unreadable (eg. corrupted) entries are treated as missing.
+    pub fn get(&self, key: &str) -> Option<CachedTransform> {
+        fs::read_to_string(self.entry_path(key))
+            .ok()
+            .and_then(|entry| serde_json::from_str(&entry).ok())
+    }
+
+    /// Store the transform of the key.
+    pub fn set(&self, key: &str, cached: &CachedTransform) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        // Write to a temporary file first not to leave partially written entries.
+        let entry_path = self.entry_path(key);
+        let temp_path = entry_path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string(cached)?)?;
+        fs::rename(temp_path, entry_path)
+    }
+
+    /// Remove all entries of the cache.
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    /// Same as `transform`, but returns the cached output if the source is not changed.
+    ///
+    /// Failing to store the output is ignored because the cache is optional.
+    pub fn transform(
+        &self,
+        path: &Path,
+        module_name: &str,
+        source: String,
+        config: &GlobalEsmConfig,
+    ) -> Result<CachedTransform, Vec<Diagnostic>> {
+        let key = TransformCache::key(module_name, &source, config);
+        if let Some(cached) = self.get(&key) {
+            return Ok(cached);
+        }
+
+        let (output, dependencies) = transform_file(path, module_name, source, config)?;
+        let cached = CachedTransform {
+            code: output.code,
+            map: output.map,
+            dependencies,
+        };
+        let _ = self.set(&key, &cached);
+        Ok(cached)
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(key).with_extension("json")
+    }
+}
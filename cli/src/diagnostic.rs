@@ -0,0 +1,59 @@
+use std::{fmt, path::PathBuf};
+
+use swc_core::common::{SourceMap, Span};
+
+/// An error or warning of the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub file: PathBuf,
+    /// 1-based line (`0` when the location is unknown).
+    pub line: usize,
+    /// 1-based column (`0` when the location is unknown).
+    pub column: usize,
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Diagnostic of the whole file (eg. IO errors).
+    pub fn new(file: impl Into<PathBuf>, message: impl Into<String>) -> Self {
+        Diagnostic {
+            file: file.into(),
+            line: 0,
+            column: 0,
+            message: message.into(),
+        }
+    }
+
+    /// Diagnostic at the span of the source map.
+    pub(crate) fn with_span(
+        cm: &SourceMap,
+        file: impl Into<PathBuf>,
+        span: Span,
+        message: impl Into<String>,
+    ) -> Self {
+        let loc = cm.lookup_char_pos(span.lo);
+        Diagnostic {
+            file: file.into(),
+            line: loc.line,
+            column: loc.col.0 + 1,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}: {}", self.file.display(), self.message)
+        } else {
+            write!(
+                f,
+                "{}:{}:{}: {}",
+                self.file.display(),
+                self.line,
+                self.column,
+                self.message
+            )
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::{
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::is_source_file;
+
+/// Collect source files of the inputs (files or directories).
+///
+/// Directories are searched recursively except `node_modules` and hidden directories.
+pub fn collect_files(inputs: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for input in inputs {
+        if input.is_dir() {
+            collect_dir(input, &mut files)?;
+        } else {
+            files.push(input.clone());
+        }
+    }
+    Ok(files)
+}
+
+fn collect_dir(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for path in entries {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_dir() {
+            if file_name != "node_modules" && !file_name.starts_with('.') {
+                collect_dir(&path, files)?;
+            }
+        } else if is_source_file(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the module name of the file (relative path from `root` with `/` separators).
+///
+/// eg. `src/components/Button.tsx`
+pub fn module_name(path: &Path, root: &Path) -> String {
+    let relative_path = path.strip_prefix(root).unwrap_or(path);
+    relative_path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
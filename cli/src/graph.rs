@@ -0,0 +1,308 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use swc_core::common::{sync::Lrc, FileName, Globals, SourceFile, SourceMap, Span, GLOBALS};
+use swc_global_esm::{analyze_module, GlobalEsmConfig, ModuleInfo};
+
+use crate::{
+    hmr::accepts_hot_update,
+    module_name,
+    transform::{self, parse, strip_typescript},
+    Diagnostic,
+};
+
+/// Extensions to resolve module requests without extensions, in priority order.
+const RESOLVE_EXTENSIONS: [&str; 8] = ["ts", "tsx", "js", "jsx", "mjs", "cjs", "mts", "cts"];
+
+/// A module source that the module depends on.
+#[derive(Debug, Clone)]
+pub struct Dependency {
+    /// Module source of the import (or re-export) declaration.
+    pub specifier: String,
+    /// Module name requested to the runtime (`importPaths` applied).
+    pub request: String,
+    /// Id of the resolved module in the graph.
+    pub resolved: Option<String>,
+    /// Span of the first declaration that imports the module.
+    pub span: Span,
+}
+
+/// A module of the graph.
+#[derive(Debug, Clone)]
+pub struct ModuleNode {
+    /// Module name registered to the runtime (relative path from the root).
+    pub id: String,
+    pub path: PathBuf,
+    pub info: ModuleInfo,
+    /// `true` when the module accepts hot updates (`import.meta.hot.accept()`).
+    pub accepts_hot_update: bool,
+    /// Unique dependencies in source order.
+    pub dependencies: Vec<Dependency>,
+}
+
+/// Dependency graph of the modules keyed by module id.
+pub struct ModuleGraph {
+    cm: Lrc<SourceMap>,
+    modules: BTreeMap<String, ModuleNode>,
+}
+
+impl ModuleGraph {
+    /// Build the graph of the files.
+    ///
+    /// Files that can't be read or parsed are reported as diagnostics and excluded from the graph.
+    pub fn build(
+        files: &[PathBuf],
+        root: &Path,
+        config: &GlobalEsmConfig,
+    ) -> (ModuleGraph, Vec<Diagnostic>) {
+        let mut diagnostics = Vec::new();
+        let sources = files
+            .iter()
+            .filter_map(|path| match fs::read_to_string(path) {
+                Ok(source) => Some((path.clone(), source)),
+                Err(err) => {
+                    diagnostics.push(Diagnostic::new(path, err.to_string()));
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let (graph, parse_diagnostics) = ModuleGraph::from_sources(sources, root, config);
+        diagnostics.extend(parse_diagnostics);
+        (graph, diagnostics)
+    }
+
+    /// Build the graph of the sources (file path and source code).
+    pub fn from_sources(
+        sources: Vec<(PathBuf, String)>,
+        root: &Path,
+        config: &GlobalEsmConfig,
+    ) -> (ModuleGraph, Vec<Diagnostic>) {
+        let cm: Lrc<SourceMap> = Default::default();
+        let mut diagnostics = Vec::new();
+        let mut modules = BTreeMap::new();
+        let mut module_sources = BTreeMap::new();
+
+        for (path, source) in sources {
+            let id = module_name(&path, root);
+            let module = match parse(&cm, &path, &id, source, None) {
+                Ok(module) => module,
+                Err(parse_diagnostics) => {
+                    diagnostics.extend(parse_diagnostics);
+                    continue;
+                }
+            };
+            // Analyze the module as the transform sees it (eg. type-only imports are elided).
+            let module = GLOBALS.set(&Globals::new(), || {
+                strip_typescript(&cm, &path, module, &Default::default()).expect_module()
+            });
+            let info = analyze_module(&module);
+            let accepts_hot_update = accepts_hot_update(&module);
+            module_sources.insert(id.clone(), transform::module_sources(&module));
+            modules.insert(
+                id.clone(),
+                ModuleNode {
+                    id,
+                    path,
+                    info,
+                    accepts_hot_update,
+                    dependencies: Vec::new(),
+                },
+            );
+        }
+
+        let ids = modules.keys().cloned().collect::<BTreeSet<_>>();
+        modules.values_mut().for_each(|node| {
+            node.dependencies = module_sources
+                .remove(&node.id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(specifier, span)| {
+                    let request = config.to_actual_path(&specifier);
+                    Dependency {
+                        specifier,
+                        resolved: resolve(&ids, &node.id, &request),
+                        request,
+                        span,
+                    }
+                })
+                .collect();
+        });
+
+        (ModuleGraph { cm, modules }, diagnostics)
+    }
+
+    pub fn module(&self, id: &str) -> Option<&ModuleNode> {
+        self.modules.get(id)
+    }
+
+    /// Returns the modules sorted by id.
+    pub fn modules(&self) -> impl Iterator<Item = &ModuleNode> {
+        self.modules.values()
+    }
+
+    /// Returns ids of the resolved dependencies of the module.
+    pub fn dependencies_of<'a>(&'a self, id: &str) -> impl Iterator<Item = &'a str> {
+        self.modules
+            .get(id)
+            .into_iter()
+            .flat_map(|node| &node.dependencies)
+            .filter_map(|dep| dep.resolved.as_deref())
+    }
+
+    /// Returns ids of the modules that depend on the module.
+    pub fn importers_of<'a>(&'a self, id: &'a str) -> impl Iterator<Item = &'a str> {
+        self.modules
+            .values()
+            .filter(move |node| {
+                node.dependencies
+                    .iter()
+                    .any(|dep| dep.resolved.as_deref() == Some(id))
+            })
+            .map(|node| node.id.as_str())
+    }
+
+    /// Returns the source file of the module.
+    pub(crate) fn source_file(&self, node: &ModuleNode) -> Option<Lrc<SourceFile>> {
+        self.cm
+            .get_source_file(&FileName::Real(PathBuf::from(&node.id)))
+    }
+
+    /// Returns a diagnostic at the span of the module.
+    pub fn diagnostic(
+        &self,
+        node: &ModuleNode,
+        span: Span,
+        message: impl Into<String>,
+    ) -> Diagnostic {
+        Diagnostic::with_span(&self.cm, &node.path, span, message)
+    }
+
+    /// Returns diagnostics of the imports that are not resolved to any module of the graph.
+    pub fn unresolved_imports(&self) -> Vec<Diagnostic> {
+        self.modules
+            .values()
+            .flat_map(|node| {
+                node.dependencies
+                    .iter()
+                    .filter(|dep| dep.resolved.is_none())
+                    .map(move |dep| {
+                        self.diagnostic(
+                            node,
+                            dep.span,
+                            format!("module \"{}\" is never registered", dep.request),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the import chains of the circular dependencies.
+    ///
+    /// eg. `["a.js", "b.js", "a.js"]`
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let mut cycles = Vec::new();
+        let mut visited = BTreeSet::new();
+        let mut stack = Vec::new();
+        self.modules.keys().for_each(|id| {
+            self.find_cycles(id, &mut visited, &mut stack, &mut cycles);
+        });
+        cycles
+    }
+
+    fn find_cycles<'a>(
+        &'a self,
+        id: &'a str,
+        visited: &mut BTreeSet<&'a str>,
+        stack: &mut Vec<&'a str>,
+        cycles: &mut Vec<Vec<String>>,
+    ) {
+        if let Some(index) = stack.iter().position(|&on_stack| on_stack == id) {
+            cycles.push(
+                stack[index..]
+                    .iter()
+                    .chain(std::iter::once(&id))
+                    .map(|id| id.to_string())
+                    .collect(),
+            );
+            return;
+        }
+        if !visited.insert(id) {
+            return;
+        }
+
+        stack.push(id);
+        self.dependencies_of(id)
+            .for_each(|dep| self.find_cycles(dep, visited, stack, cycles));
+        stack.pop();
+    }
+
+    /// Returns the module ids in evaluation order (dependencies first, same as ES modules).
+    pub fn evaluation_order(&self) -> Vec<String> {
+        self.evaluation_order_from(self.modules.keys().map(String::as_str))
+    }
+
+    /// Returns ids of the entries and their dependencies in evaluation order.
+    pub fn evaluation_order_from<'a>(
+        &self,
+        entries: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<String> {
+        let mut order = Vec::new();
+        let mut visited = BTreeSet::new();
+        entries
+            .into_iter()
+            .for_each(|id| self.visit_post_order(id, &mut visited, &mut order));
+        order
+    }
+
+    fn visit_post_order(&self, id: &str, visited: &mut BTreeSet<String>, order: &mut Vec<String>) {
+        if !self.modules.contains_key(id) || !visited.insert(id.to_string()) {
+            return;
+        }
+
+        self.dependencies_of(id)
+            .for_each(|dep| self.visit_post_order(dep, visited, order));
+        order.push(id.to_string());
+    }
+}
+
+/// Resolve the request to a module id of the graph.
+///
+/// Relative requests are resolved from the importer and extensions (or `index` files) can be omitted.
+pub fn resolve(ids: &BTreeSet<String>, importer: &str, request: &str) -> Option<String> {
+    if ids.contains(request) {
+        return Some(request.to_string());
+    }
+
+    let base = if request.starts_with("./") || request.starts_with("../") {
+        let dir = importer.rsplit_once('/').map_or("", |(dir, _)| dir);
+        normalize(&format!("{dir}/{request}"))
+    } else {
+        normalize(request)
+    };
+
+    std::iter::once(base.clone())
+        .chain(RESOLVE_EXTENSIONS.iter().map(|ext| format!("{base}.{ext}")))
+        .chain(
+            RESOLVE_EXTENSIONS
+                .iter()
+                .map(|ext| format!("{base}/index.{ext}")),
+        )
+        .find(|candidate| ids.contains(candidate))
+}
+
+/// Normalize `.` and `..` segments of the `/` separated path.
+fn normalize(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    path.split('/').for_each(|segment| match segment {
+        "" | "." => {}
+        ".." => {
+            segments.pop();
+        }
+        segment => segments.push(segment),
+    });
+    segments.join("/")
+}
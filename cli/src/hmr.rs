@@ -0,0 +1,156 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use swc_core::{
+    common::{comments::SingleThreadedComments, sync::Lrc, Globals, SourceMap, GLOBALS},
+    ecma::{
+        ast::*,
+        visit::{noop_visit_type, Visit, VisitWith},
+    },
+};
+use swc_global_esm::{analyze_module, ModuleInfo};
+
+use crate::{
+    transform::{parse, strip_typescript},
+    Diagnostic, ModuleGraph, ModuleNode,
+};
+
+/// Update payload of the changed module for the clients.
+///
+/// eg. `{ "type": "update", "modules": ["src/app.tsx", "src/index.ts"] }`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum HmrUpdate {
+    /// Re-run the modules in evaluation order (dependencies first).
+    Update { modules: Vec<String> },
+    /// Reload the whole page because no module accepts the update.
+    FullReload { reason: String },
+}
+
+/// Returns the update payload of the changed module.
+///
+/// The update propagates to the importers until it reaches the modules that accept hot updates
+/// (`import.meta.hot.accept()`). The changed module only accepts its own update when its export
+/// names are not changed (or it has no importers), because the importers still hold the bindings
+/// of the old exports.
+pub fn hmr_update(
+    graph: &ModuleGraph,
+    id: &str,
+    old_source: String,
+    new_source: String,
+) -> Result<HmrUpdate, Vec<Diagnostic>> {
+    let Some(node) = graph.module(id) else {
+        return Err(vec![Diagnostic::new(id, "module is not in the graph")]);
+    };
+    let (old_info, _) = analyze_source(node, old_source)?;
+    let (new_info, accepts_hot_update) = analyze_source(node, new_source)?;
+
+    if accepts_hot_update
+        && (export_names_of(&old_info) == export_names_of(&new_info)
+            || graph.importers_of(id).next().is_none())
+    {
+        return Ok(HmrUpdate::Update {
+            modules: vec![id.to_string()],
+        });
+    }
+
+    let mut updated = BTreeSet::from([id]);
+    let mut queue = vec![id];
+    while let Some(id) = queue.pop() {
+        let mut importers = graph.importers_of(id).peekable();
+        if importers.peek().is_none() {
+            return Ok(HmrUpdate::FullReload {
+                reason: format!("\"{id}\" has no importers that accept the update"),
+            });
+        }
+
+        importers.for_each(|importer| {
+            let accepts_hot_update = graph
+                .module(importer)
+                .is_some_and(|node| node.accepts_hot_update);
+            if updated.insert(importer) && !accepts_hot_update {
+                queue.push(importer);
+            }
+        });
+    }
+
+    Ok(HmrUpdate::Update {
+        modules: graph
+            .evaluation_order()
+            .into_iter()
+            .filter(|id| updated.contains(id.as_str()))
+            .collect(),
+    })
+}
+
+/// Collect imports and exports of the module source as the graph does.
+fn analyze_source(
+    node: &ModuleNode,
+    source: String,
+) -> Result<(ModuleInfo, bool), Vec<Diagnostic>> {
+    let cm: Lrc<SourceMap> = Default::default();
+    GLOBALS.set(&Globals::new(), || {
+        let module = parse(&cm, &node.path, &node.id, source, None)?;
+        let module = strip_typescript(&cm, &node.path, module, &SingleThreadedComments::default())
+            .expect_module();
+        Ok((analyze_module(&module), accepts_hot_update(&module)))
+    })
+}
+
+/// Returns the export names including the sources of star exports.
+fn export_names_of(info: &ModuleInfo) -> BTreeSet<String> {
+    info.export_names()
+        .into_iter()
+        .map(ToString::to_string)
+        .chain(
+            info.star_exports
+                .iter()
+                .map(|star_export| format!("* from {}", star_export.module_src)),
+        )
+        .collect()
+}
+
+/// Check the module calls `import.meta.hot.accept()`.
+pub(crate) fn accepts_hot_update(module: &Module) -> bool {
+    let mut finder = HotAcceptFinder::default();
+    module.visit_with(&mut finder);
+    finder.found
+}
+
+#[derive(Default)]
+struct HotAcceptFinder {
+    found: bool,
+}
+
+impl Visit for HotAcceptFinder {
+    noop_visit_type!();
+
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        if let Callee::Expr(callee) = &call_expr.callee {
+            if let Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(accept),
+                ..
+            }) = &**callee
+            {
+                if let Expr::Member(MemberExpr {
+                    obj: meta,
+                    prop: MemberProp::Ident(hot),
+                    ..
+                }) = &**obj
+                {
+                    self.found |= accept.sym == "accept"
+                        && hot.sym == "hot"
+                        && matches!(
+                            &**meta,
+                            Expr::MetaProp(MetaPropExpr {
+                                kind: MetaPropKind::ImportMeta,
+                                ..
+                            })
+                        );
+                }
+            }
+        }
+        call_expr.visit_children_with(self);
+    }
+}
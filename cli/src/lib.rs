@@ -0,0 +1,22 @@
+//! Native API of the `global_esm` transform.
+//!
+//! Transforms files without the swc plugin host (used by the `global-esm` command).
+mod batch;
+mod bundle;
+mod cache;
+mod diagnostic;
+mod files;
+mod graph;
+mod hmr;
+mod link;
+mod transform;
+
+pub use batch::{transform_batch, BatchOptions, BatchResult};
+pub use bundle::bundle;
+pub use cache::{CachedTransform, TransformCache};
+pub use diagnostic::Diagnostic;
+pub use files::{collect_files, module_name};
+pub use graph::{resolve, Dependency, ModuleGraph, ModuleNode};
+pub use hmr::{hmr_update, HmrUpdate};
+pub use link::check_links;
+pub use transform::{is_source_file, syntax_of, transform, TransformOutput};
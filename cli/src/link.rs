@@ -0,0 +1,143 @@
+use std::collections::BTreeSet;
+
+use swc_core::{common::Span, ecma::atoms::JsWord};
+use swc_global_esm::ModuleType;
+
+use crate::{Diagnostic, ModuleGraph, ModuleNode};
+
+/// Exported names of the module including star exports.
+///
+/// `complete` is `false` when some names can't be known (eg. star exports of unresolved modules).
+struct ExportNames {
+    names: BTreeSet<JsWord>,
+    complete: bool,
+}
+
+/// Check the imports of the graph are exported by their target modules.
+///
+/// Runtime modules yield `undefined` for missing names instead of throwing a `SyntaxError`,
+/// so this reports them statically.
+///
+/// - Named imports (and re-exports) that are not exported by the target (following `export *`)
+/// - Default imports of modules without a default export
+///
+/// Targets without any ES module exports (eg. CommonJS modules) are not checked.
+pub fn check_links(graph: &ModuleGraph) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    graph.modules().for_each(|node| {
+        let imports = node.info.imports.iter().map(|import| {
+            (
+                &import.module_src,
+                import.module_type,
+                import
+                    .imported
+                    .as_ref()
+                    .unwrap_or(&import.ident)
+                    .sym
+                    .clone(),
+                import.span,
+            )
+        });
+        let re_exports = node.info.re_exports.iter().map(|re_export| {
+            (
+                &re_export.module_src,
+                re_export.module_type,
+                re_export
+                    .imported
+                    .as_ref()
+                    .map(|imported| imported.sym.clone())
+                    .unwrap_or_else(|| re_export.name.clone()),
+                re_export.span,
+            )
+        });
+
+        imports
+            .chain(re_exports)
+            .for_each(|(module_src, module_type, imported, span)| {
+                let name: JsWord = match module_type {
+                    ModuleType::Default => "default".into(),
+                    ModuleType::Named | ModuleType::DefaultAsNamed => imported,
+                    ModuleType::NamespaceOrAll => return,
+                };
+                if let Some(diagnostic) = check_import(graph, node, module_src, &name, span) {
+                    diagnostics.push(diagnostic);
+                }
+            });
+    });
+    diagnostics
+}
+
+fn check_import(
+    graph: &ModuleGraph,
+    node: &ModuleNode,
+    module_src: &str,
+    name: &JsWord,
+    span: Span,
+) -> Option<Diagnostic> {
+    let target = node
+        .dependencies
+        .iter()
+        .find(|dep| dep.specifier == module_src)
+        .and_then(|dep| dep.resolved.as_deref())
+        .and_then(|id| graph.module(id))?;
+    if !is_es_module(target) {
+        return None;
+    }
+
+    let export_names = collect_export_names(graph, target, &mut BTreeSet::new());
+    if !export_names.complete || export_names.names.contains(name) {
+        return None;
+    }
+
+    let message = if name == "default" {
+        format!("\"{}\" has no default export", target.id)
+    } else {
+        format!("\"{name}\" is not exported by \"{}\"", target.id)
+    };
+    Some(graph.diagnostic(node, span, message))
+}
+
+/// Check the module has ES module exports.
+fn is_es_module(node: &ModuleNode) -> bool {
+    !(node.info.exports.is_empty()
+        && node.info.re_exports.is_empty()
+        && node.info.star_exports.is_empty())
+}
+
+/// Returns exported names of the module following `export *` chains (`default` is not re-exported).
+fn collect_export_names<'a>(
+    graph: &'a ModuleGraph,
+    node: &'a ModuleNode,
+    visited: &mut BTreeSet<&'a str>,
+) -> ExportNames {
+    let mut export_names = ExportNames {
+        names: node.info.export_names().into_iter().cloned().collect(),
+        complete: true,
+    };
+    if !visited.insert(&node.id) {
+        return export_names;
+    }
+
+    node.info.star_exports.iter().for_each(|star_export| {
+        let target = node
+            .dependencies
+            .iter()
+            .find(|dep| dep.specifier == star_export.module_src)
+            .and_then(|dep| dep.resolved.as_deref())
+            .and_then(|id| graph.module(id));
+        match target {
+            Some(target) if is_es_module(target) => {
+                let star_names = collect_export_names(graph, target, visited);
+                export_names.complete &= star_names.complete;
+                export_names.names.extend(
+                    star_names
+                        .names
+                        .into_iter()
+                        .filter(|name| name != "default"),
+                );
+            }
+            _ => export_names.complete = false,
+        }
+    });
+    export_names
+}
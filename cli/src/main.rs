@@ -0,0 +1,238 @@
+use std::{
+    fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use global_esm::{
+    bundle, check_links, collect_files, module_name, transform_batch, BatchOptions, BatchResult,
+    Diagnostic, ModuleGraph, TransformCache, TransformOutput,
+};
+use swc_global_esm::{generate_runtime, GlobalEsmConfig};
+
+/// Transform JS/TS files with the `global_esm` transform.
+#[derive(Debug, Parser)]
+#[command(
+    name = "global-esm",
+    version,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    args: TransformArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Bundle the entries and their dependencies into one script with a source map.
+    Bundle(BundleArgs),
+    /// Write the runtime script for the config (eg. custom global and registry names).
+    Runtime(RuntimeArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct TransformArgs {
+    /// Files or directories to transform.
+    #[arg(required = true)]
+    inputs: Vec<PathBuf>,
+
+    /// Config file of the transform (JSON, same as the plugin options).
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    /// Output directory of the transformed files and source maps.
+    #[arg(short, long, default_value = "dist")]
+    out_dir: PathBuf,
+
+    /// Root directory of the module names. Defaults to the current directory.
+    #[arg(long)]
+    root: Option<PathBuf>,
+
+    /// Report diagnostics without writing files.
+    ///
+    /// Named and default imports that are not exported by their target files are also reported.
+    #[arg(long)]
+    check: bool,
+
+    /// Cache directory of the transformed files. Unchanged files are not transformed again.
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+
+    /// Number of threads to transform files. Defaults to the available parallelism.
+    #[arg(short = 'j', long)]
+    threads: Option<NonZeroUsize>,
+}
+
+#[derive(Debug, clap::Args)]
+struct BundleArgs {
+    /// Files or directories of the modules that can be bundled.
+    #[arg(required = true)]
+    inputs: Vec<PathBuf>,
+
+    /// Entry files of the bundle.
+    #[arg(short, long = "entry", required = true)]
+    entries: Vec<PathBuf>,
+
+    /// Config file of the transform (JSON, same as the plugin options).
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    /// Output file of the bundle. The source map is written next to it (`.js.map`).
+    #[arg(short, long, default_value = "dist/bundle.js")]
+    out_file: PathBuf,
+
+    /// Root directory of the module names. Defaults to the current directory.
+    #[arg(long)]
+    root: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+struct RuntimeArgs {
+    /// Config file of the transform (JSON, same as the plugin options).
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    /// Output file of the runtime.
+    #[arg(short, long, default_value = "dist/runtime.js")]
+    out_file: PathBuf,
+}
+
+fn load_config(path: Option<&Path>) -> anyhow::Result<GlobalEsmConfig> {
+    let Some(path) = path else {
+        return Ok(GlobalEsmConfig::default());
+    };
+
+    let config = fs::read_to_string(path)
+        .with_context(|| format!("failed to read config file: {}", path.display()))?;
+    serde_json::from_str(&config)
+        .with_context(|| format!("invalid config file: {}", path.display()))
+}
+
+fn root_dir(root: Option<&PathBuf>) -> anyhow::Result<PathBuf> {
+    match root {
+        Some(root) => Ok(root.clone()),
+        None => Ok(std::env::current_dir()?),
+    }
+}
+
+/// Write the code with its source map (`<out_path>.map`).
+fn write_output(out_path: &Path, output: TransformOutput) -> Vec<Diagnostic> {
+    let map_path = out_path.with_extension("js.map");
+    let map_file_name = map_path.file_name().unwrap_or_default().to_string_lossy();
+    let code = format!("{}\n//# sourceMappingURL={map_file_name}\n", output.code);
+
+    out_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(out_path, code))
+        .and_then(|_| fs::write(&map_path, output.map))
+        .err()
+        .map(|err| vec![Diagnostic::new(out_path, err.to_string())])
+        .unwrap_or_default()
+}
+
+/// Print the diagnostics and returns the exit code.
+fn report(diagnostics: &[Diagnostic], summary: impl FnOnce() -> String) -> ExitCode {
+    diagnostics
+        .iter()
+        .for_each(|diagnostic| eprintln!("error: {diagnostic}"));
+
+    if diagnostics.is_empty() {
+        eprintln!("{}", summary());
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("Found {} error(s)", diagnostics.len());
+        ExitCode::FAILURE
+    }
+}
+
+/// Write the output of the transformed file with its source map.
+fn write_result(args: &TransformArgs, result: BatchResult) -> Vec<Diagnostic> {
+    match result.output {
+        Ok(_) if args.check => Vec::new(),
+        Ok(output) => {
+            let out_path = args.out_dir.join(&result.module_name).with_extension("js");
+            write_output(&out_path, output)
+        }
+        Err(diagnostics) => diagnostics,
+    }
+}
+
+fn run_transform(args: &TransformArgs) -> anyhow::Result<ExitCode> {
+    let config = load_config(args.config.as_deref())?;
+    let root = root_dir(args.root.as_ref())?;
+    let files = collect_files(&args.inputs).context("failed to read inputs")?;
+
+    let options = BatchOptions {
+        threads: args.threads,
+        cache: args.cache_dir.as_ref().map(TransformCache::new),
+    };
+    let mut diagnostics = transform_batch(&files, &root, &config, &options)
+        .into_iter()
+        .flat_map(|result| write_result(args, result))
+        .collect::<Vec<_>>();
+    if args.check && diagnostics.is_empty() {
+        let (graph, _) = ModuleGraph::build(&files, &root, &config);
+        diagnostics.extend(check_links(&graph));
+    }
+
+    Ok(report(&diagnostics, || {
+        format!(
+            "{} {} file(s)",
+            if args.check { "Checked" } else { "Transformed" },
+            files.len()
+        )
+    }))
+}
+
+fn run_bundle(args: &BundleArgs) -> anyhow::Result<ExitCode> {
+    let config = load_config(args.config.as_deref())?;
+    let root = root_dir(args.root.as_ref())?;
+    let files = collect_files(&args.inputs).context("failed to read inputs")?;
+
+    let (graph, mut diagnostics) = ModuleGraph::build(&files, &root, &config);
+    if diagnostics.is_empty() {
+        let entries = args
+            .entries
+            .iter()
+            .map(|entry| module_name(entry, &root))
+            .collect::<Vec<_>>();
+        let entries = entries.iter().map(String::as_str).collect::<Vec<_>>();
+        match bundle(&graph, &entries, &config) {
+            Ok(output) => diagnostics.extend(write_output(&args.out_file, output)),
+            Err(bundle_diagnostics) => diagnostics.extend(bundle_diagnostics),
+        }
+    }
+
+    Ok(report(&diagnostics, || {
+        format!("Bundled {}", args.out_file.display())
+    }))
+}
+
+fn run_runtime(args: &RuntimeArgs) -> anyhow::Result<ExitCode> {
+    let config = load_config(args.config.as_deref())?;
+    if let Some(parent) = args.out_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&args.out_file, generate_runtime(&config))
+        .with_context(|| format!("failed to write runtime: {}", args.out_file.display()))?;
+
+    eprintln!("Generated {}", args.out_file.display());
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let cli = Cli::parse();
+    match &cli.command {
+        Some(Command::Bundle(args)) => run_bundle(args),
+        Some(Command::Runtime(args)) => run_runtime(args),
+        None => run_transform(&cli.args),
+    }
+}
@@ -0,0 +1,245 @@
+use std::path::{Path, PathBuf};
+
+use swc_core::{
+    common::{
+        comments::SingleThreadedComments, sync::Lrc, FileName, Globals, Mark, SourceMap, Span,
+        Spanned, GLOBALS,
+    },
+    ecma::{
+        ast::{EsVersion, Module, ModuleDecl, ModuleItem, Program},
+        codegen::{text_writer::JsWriter, Config, Emitter},
+        parser::{parse_file_as_module, EsConfig, Syntax, TsConfig},
+        transforms::{
+            base::{fixer::fixer, hygiene::hygiene, resolver},
+            typescript::{strip, tsx},
+        },
+        visit::FoldWith,
+    },
+};
+use swc_global_esm::{global_esm, GlobalEsmConfig};
+
+use crate::Diagnostic;
+
+/// Source file extensions that can be transformed.
+const SOURCE_EXTENSIONS: [&str; 8] = ["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts"];
+
+/// Transformed code of the file.
+#[derive(Debug, Clone)]
+pub struct TransformOutput {
+    pub code: String,
+    /// Source map of the code (JSON).
+    pub map: String,
+}
+
+/// Check the file is a source file that can be transformed (declaration files are excluded).
+pub fn is_source_file(path: &Path) -> bool {
+    let file_name = path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy())
+        .unwrap_or_default();
+
+    !file_name.ends_with(".d.ts")
+        && path
+            .extension()
+            .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext.to_string_lossy().as_ref()))
+}
+
+/// Returns the parser syntax of the file by its extension.
+pub fn syntax_of(path: &Path) -> Syntax {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("ts" | "mts" | "cts") => Syntax::Typescript(TsConfig::default()),
+        Some("tsx") => Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    }
+}
+
+/// Parse the source as an ES module.
+///
+/// Recovered errors are also reported as diagnostics.
+pub(crate) fn parse(
+    cm: &SourceMap,
+    path: &Path,
+    module_name: &str,
+    source: String,
+    comments: Option<&SingleThreadedComments>,
+) -> Result<Module, Vec<Diagnostic>> {
+    let fm = cm.new_source_file(FileName::Real(PathBuf::from(module_name)), source);
+    let mut errors = Vec::new();
+    let module = parse_file_as_module(
+        &fm,
+        syntax_of(path),
+        EsVersion::latest(),
+        comments.map(|comments| comments as _),
+        &mut errors,
+    );
+
+    let diagnostic = |err: swc_core::ecma::parser::error::Error| {
+        Diagnostic::with_span(cm, path, err.span(), err.into_kind().msg())
+    };
+    match module {
+        Ok(module) if errors.is_empty() => Ok(module),
+        Ok(_) => Err(errors.into_iter().map(diagnostic).collect()),
+        Err(err) => Err(std::iter::once(err).chain(errors).map(diagnostic).collect()),
+    }
+}
+
+/// Resolve the module and strip TypeScript syntax by the file extension.
+///
+/// Must be called in `GLOBALS`.
+pub(crate) fn strip_typescript(
+    cm: &Lrc<SourceMap>,
+    path: &Path,
+    module: Module,
+    comments: &SingleThreadedComments,
+) -> Program {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    let syntax = syntax_of(path);
+    let program = Program::Module(module).fold_with(&mut resolver(
+        unresolved_mark,
+        top_level_mark,
+        syntax.typescript(),
+    ));
+
+    if syntax.jsx() && syntax.typescript() {
+        // Keep imports of the JSX pragma (eg. `React`).
+        program.fold_with(&mut tsx(
+            cm.clone(),
+            Default::default(),
+            Default::default(),
+            comments,
+            top_level_mark,
+        ))
+    } else if syntax.typescript() {
+        program.fold_with(&mut strip(top_level_mark))
+    } else {
+        program
+    }
+}
+
+/// Returns the unique module sources of the import and export declarations in source order
+/// (including side effect imports) with the span of the first declaration.
+pub(crate) fn module_sources(module: &Module) -> Vec<(String, Span)> {
+    let mut sources: Vec<(String, Span)> = Vec::new();
+    module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) if !import_decl.type_only => {
+                Some((&import_decl.src, import_decl.span))
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export))
+                if !named_export.type_only =>
+            {
+                named_export
+                    .src
+                    .as_ref()
+                    .map(|src| (src, named_export.span))
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)) if !export_all.type_only => {
+                Some((&export_all.src, export_all.span))
+            }
+            _ => None,
+        })
+        .for_each(|(src, span)| {
+            if !sources.iter().any(|(source, _)| *source == *src.value) {
+                sources.push((src.value.to_string(), span));
+            }
+        });
+    sources
+}
+
+/// Parse the source and apply the `global_esm` transform with TypeScript stripped.
+///
+/// Returns the program with the module sources it depends on.
+/// Must be called in `GLOBALS`.
+pub(crate) fn transform_program(
+    cm: &Lrc<SourceMap>,
+    comments: &SingleThreadedComments,
+    path: &Path,
+    module_name: &str,
+    source: String,
+    config: &GlobalEsmConfig,
+) -> Result<(Program, Vec<String>), Vec<Diagnostic>> {
+    let module = parse(cm, path, module_name, source, Some(comments))?;
+    let module = strip_typescript(cm, path, module, comments).expect_module();
+    let dependencies = module_sources(&module)
+        .into_iter()
+        .map(|(src, _)| src)
+        .collect();
+
+    let program = Program::Module(module)
+        .fold_with(&mut global_esm(
+            module_name.to_string(),
+            config.clone(),
+            comments.clone(),
+        ))
+        .fold_with(&mut hygiene())
+        .fold_with(&mut fixer(Some(comments)));
+    Ok((program, dependencies))
+}
+
+/// Emit the program with its source map.
+pub(crate) fn emit(
+    cm: &Lrc<SourceMap>,
+    comments: &SingleThreadedComments,
+    path: &Path,
+    program: &Program,
+) -> Result<TransformOutput, Vec<Diagnostic>> {
+    let mut code = Vec::new();
+    let mut mappings = Vec::new();
+    Emitter {
+        cfg: Config::default(),
+        cm: cm.clone(),
+        comments: Some(comments),
+        wr: JsWriter::new(cm.clone(), "\n", &mut code, Some(&mut mappings)),
+    }
+    .emit_program(program)
+    .map_err(|err| vec![Diagnostic::new(path, err.to_string())])?;
+
+    let mut map = Vec::new();
+    cm.build_source_map(&mappings)
+        .to_writer(&mut map)
+        .map_err(|err| vec![Diagnostic::new(path, err.to_string())])?;
+
+    Ok(TransformOutput {
+        code: String::from_utf8_lossy(&code).into_owned(),
+        map: String::from_utf8_lossy(&map).into_owned(),
+    })
+}
+
+/// Transform the source of the file with `global_esm`.
+///
+/// `module_name` is the name of the module registered to the runtime (eg. relative path of the file).
+/// TypeScript syntax is stripped and JSX is kept as it is.
+pub fn transform(
+    path: &Path,
+    module_name: &str,
+    source: String,
+    config: &GlobalEsmConfig,
+) -> Result<TransformOutput, Vec<Diagnostic>> {
+    transform_file(path, module_name, source, config).map(|(output, _)| output)
+}
+
+/// Same as `transform`, but also returns the module sources the module depends on.
+pub(crate) fn transform_file(
+    path: &Path,
+    module_name: &str,
+    source: String,
+    config: &GlobalEsmConfig,
+) -> Result<(TransformOutput, Vec<String>), Vec<Diagnostic>> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let comments = SingleThreadedComments::default();
+
+    GLOBALS.set(&Globals::new(), || {
+        let (program, dependencies) =
+            transform_program(&cm, &comments, path, module_name, source, config)?;
+        Ok((emit(&cm, &comments, path, &program)?, dependencies))
+    })
+}
@@ -0,0 +1,59 @@
+use std::{fs, num::NonZeroUsize, path::PathBuf};
+
+use global_esm::{transform, transform_batch, BatchOptions};
+use swc_global_esm::GlobalEsmConfig;
+
+#[test]
+fn transform_files_in_order() {
+    let root = std::env::temp_dir().join(format!("global-esm-batch-{}", std::process::id()));
+    fs::create_dir_all(root.join("src")).unwrap();
+
+    let mut files = (0..16)
+        .map(|index| {
+            let path = root.join(format!("src/module_{index}.ts"));
+            fs::write(
+                &path,
+                format!("import {{ value }} from './module_{}';\nexport const value_{index}: number = value;", index + 1),
+            )
+            .unwrap();
+            path
+        })
+        .collect::<Vec<_>>();
+    files.push(root.join("src/missing.ts"));
+
+    let config = GlobalEsmConfig {
+        runtime_module: true,
+        ..Default::default()
+    };
+    let results = transform_batch(
+        &files,
+        &root,
+        &config,
+        &BatchOptions {
+            threads: NonZeroUsize::new(4),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        results
+            .iter()
+            .map(|result| &result.path)
+            .collect::<Vec<_>>(),
+        files.iter().collect::<Vec<_>>()
+    );
+    results[..16]
+        .iter()
+        .enumerate()
+        .for_each(|(index, result)| {
+            let module_name = format!("src/module_{index}.ts");
+            let source = fs::read_to_string(root.join(&module_name)).unwrap();
+            let expected =
+                transform(&PathBuf::from(&module_name), &module_name, source, &config).unwrap();
+            assert_eq!(result.module_name, module_name);
+            assert_eq!(result.output.as_ref().unwrap().code, expected.code);
+        });
+    assert!(results[16].output.is_err());
+
+    fs::remove_dir_all(root).unwrap();
+}
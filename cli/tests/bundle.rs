@@ -0,0 +1,104 @@
+mod common;
+
+use common::build;
+use global_esm::bundle;
+use swc_global_esm::{GlobalEsmConfig, Wrap};
+
+#[test]
+fn bundle_entry_graph() {
+    let graph = build(&[
+        (
+            "src/index.ts",
+            "import React from 'react';\nimport { App } from './app';\nReact.render(App);",
+        ),
+        (
+            "src/app.tsx",
+            "import { format } from './utils';\nexport const App = format('app');",
+        ),
+        (
+            "src/utils.ts",
+            "export const format = (value: string) => value;",
+        ),
+        ("src/unused.ts", "export const unused = 1;"),
+    ]);
+
+    let output = bundle(&graph, &["src/index.ts"], &GlobalEsmConfig::default()).unwrap();
+    let positions = [
+        "Object.defineProperty(globalObject, '__modules'",
+        "global.__modules.init(\"src/utils.ts\")",
+        "global.__modules.import(\"src/utils.ts\")",
+        "global.__modules.import(\"src/app.tsx\")",
+    ]
+    .map(|code| output.code.find(code).unwrap());
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(output.code.contains("global.__modules.import(\"react\")"));
+    assert!(!output.code.contains("src/unused.ts"));
+    assert!(!output.code.contains("export {"));
+
+    let map: serde_json::Value = serde_json::from_str(&output.map).unwrap();
+    assert_eq!(
+        map["sources"],
+        serde_json::json!([
+            "global-esm/runtime.js",
+            "src/utils.ts",
+            "src/app.tsx",
+            "src/index.ts"
+        ])
+    );
+}
+
+#[test]
+fn isolate_module_scopes() {
+    let graph = build(&[
+        (
+            "a.js",
+            "import { b } from './b';\nconst value = b;\nexport { value };",
+        ),
+        ("b.js", "const value = 1;\nexport { value as b };"),
+    ]);
+
+    let output = bundle(&graph, &["a.js"], &GlobalEsmConfig::default()).unwrap();
+    assert_eq!(output.code.matches("const value = ").count(), 2);
+
+    // Blocks leak `var` and function declarations, so modules are always wrapped with an IIFE.
+    let config = GlobalEsmConfig {
+        wrap: Wrap::Block,
+        ..Default::default()
+    };
+    let output = bundle(&graph, &["a.js"], &config).unwrap();
+    assert_eq!(output.code.matches("(()=>{").count(), 2);
+}
+
+#[test]
+fn report_top_level_await_without_async_mode() {
+    let graph = build(&[(
+        "a.js",
+        "const value = await Promise.resolve(1);\nexport { value };",
+    )]);
+
+    let diagnostics = bundle(&graph, &["a.js"], &GlobalEsmConfig::default()).unwrap_err();
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "a.js: top-level `await` is not supported in the bundle without `asyncMode`"
+    );
+
+    let config = GlobalEsmConfig {
+        async_mode: true,
+        ..Default::default()
+    };
+    let output = bundle(&graph, &["a.js"], &config).unwrap();
+    assert!(output
+        .code
+        .contains("global.__modules.asyncModule(\"a.js\", [], async ()=>{"));
+}
+
+#[test]
+fn report_missing_entries() {
+    let graph = build(&[("a.js", "export const a = 1;")]);
+
+    let diagnostics = bundle(&graph, &["b.js"], &GlobalEsmConfig::default()).unwrap_err();
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "b.js: entry module is not in the graph"
+    );
+}
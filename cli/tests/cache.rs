@@ -0,0 +1,119 @@
+mod common;
+
+use std::{collections::HashMap, fs, path::Path};
+
+use common::runtime_config;
+use global_esm::{transform, TransformCache};
+use swc_global_esm::GlobalEsmConfig;
+
+const SOURCE: &str =
+    "import { format } from './format';\nimport './polyfill';\nexport const App = format;";
+
+fn cache_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("global-esm-cache-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn cache_transform_outputs() {
+    let dir = cache_dir("outputs");
+    let cache = TransformCache::new(&dir);
+    let path = Path::new("src/app.ts");
+    let config = runtime_config();
+
+    let cached = cache
+        .transform(path, "src/app.ts", SOURCE.to_string(), &config)
+        .unwrap();
+    let output = transform(path, "src/app.ts", SOURCE.to_string(), &config).unwrap();
+    assert_eq!(cached.code, output.code);
+    assert_eq!(cached.map, output.map);
+    assert_eq!(cached.dependencies, vec!["./format", "./polyfill"]);
+
+    let key = TransformCache::key("src/app.ts", SOURCE, &config);
+    assert_eq!(cache.get(&key), Some(cached.clone()));
+    assert_eq!(
+        cache
+            .transform(path, "src/app.ts", SOURCE.to_string(), &config)
+            .unwrap(),
+        cached
+    );
+
+    cache.clear().unwrap();
+    assert_eq!(cache.get(&key), None);
+}
+
+#[test]
+fn invalidate_by_key_parts() {
+    let config = runtime_config();
+    let key = TransformCache::key("src/app.ts", SOURCE, &config);
+
+    assert_ne!(key, TransformCache::key("src/main.ts", SOURCE, &config));
+    assert_ne!(
+        key,
+        TransformCache::key("src/app.ts", &format!("{SOURCE}\n"), &config)
+    );
+    assert_ne!(
+        key,
+        TransformCache::key("src/app.ts", SOURCE, &GlobalEsmConfig::default())
+    );
+}
+
+#[test]
+fn invalidate_by_transform_version() {
+    let config = runtime_config();
+    let key = TransformCache::key("src/app.ts", SOURCE, &config);
+
+    assert_eq!(
+        key,
+        TransformCache::key_with_version(swc_global_esm::VERSION, "src/app.ts", SOURCE, &config)
+    );
+    assert_ne!(
+        key,
+        TransformCache::key_with_version("0.0.0-changed", "src/app.ts", SOURCE, &config)
+    );
+}
+
+#[test]
+fn stable_key_of_import_paths() {
+    let import_paths = (0..32)
+        .map(|index| (format!("module-{index}"), format!("path-{index}")))
+        .collect::<HashMap<_, _>>();
+    let config = |import_paths: HashMap<String, String>| GlobalEsmConfig {
+        import_paths: Some(import_paths),
+        ..runtime_config()
+    };
+
+    // Maps collected again have different iteration orders.
+    assert_eq!(
+        TransformCache::key("src/app.ts", SOURCE, &config(import_paths.clone())),
+        TransformCache::key(
+            "src/app.ts",
+            SOURCE,
+            &config(import_paths.into_iter().collect())
+        )
+    );
+}
+
+#[test]
+fn ignore_corrupted_entries() {
+    let dir = cache_dir("corrupted");
+    let cache = TransformCache::new(&dir);
+    let config = runtime_config();
+    let key = TransformCache::key("src/app.ts", SOURCE, &config);
+
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(format!("{key}.json")), "{").unwrap();
+    assert_eq!(cache.get(&key), None);
+
+    let cached = cache
+        .transform(
+            Path::new("src/app.ts"),
+            "src/app.ts",
+            SOURCE.to_string(),
+            &config,
+        )
+        .unwrap();
+    assert_eq!(cache.get(&key), Some(cached));
+    cache.clear().unwrap();
+}
@@ -0,0 +1,29 @@
+// Each test crate uses a subset of the helpers.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+use global_esm::ModuleGraph;
+use swc_global_esm::GlobalEsmConfig;
+
+/// Build the module graph of the sources with the default config.
+pub fn build(sources: &[(&str, &str)]) -> ModuleGraph {
+    let (graph, diagnostics) = ModuleGraph::from_sources(
+        sources
+            .iter()
+            .map(|(path, source)| (PathBuf::from(path), source.to_string()))
+            .collect(),
+        Path::new(""),
+        &GlobalEsmConfig::default(),
+    );
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    graph
+}
+
+/// Returns the default config in runtime mode.
+pub fn runtime_config() -> GlobalEsmConfig {
+    GlobalEsmConfig {
+        runtime_module: true,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::build;
+
+#[test]
+fn resolve_relative_imports() {
+    let graph = build(&[
+        (
+            "src/index.ts",
+            "import { App } from './app';\nexport * from './utils';\nApp;",
+        ),
+        (
+            "src/app.tsx",
+            "import { format } from './utils/format';\nexport const App = format;",
+        ),
+        ("src/utils/index.ts", "export { format } from './format';"),
+        ("src/utils/format.ts", "export const format = 1;"),
+    ]);
+
+    let index = graph.module("src/index.ts").unwrap();
+    assert_eq!(
+        index
+            .dependencies
+            .iter()
+            .map(|dep| dep.resolved.as_deref())
+            .collect::<Vec<_>>(),
+        vec![Some("src/app.tsx"), Some("src/utils/index.ts")]
+    );
+    assert!(graph.unresolved_imports().is_empty());
+    assert!(graph.cycles().is_empty());
+    assert_eq!(
+        graph.evaluation_order(),
+        vec![
+            "src/utils/format.ts",
+            "src/app.tsx",
+            "src/utils/index.ts",
+            "src/index.ts"
+        ]
+    );
+}
+
+#[test]
+fn include_side_effect_imports() {
+    let graph = build(&[
+        (
+            "src/index.ts",
+            "import './polyfill';\nimport { App } from './app';\nApp;",
+        ),
+        ("src/polyfill.ts", "globalThis.polyfilled = true;"),
+        ("src/app.ts", "export const App = 1;"),
+    ]);
+
+    assert_eq!(
+        graph.dependencies_of("src/index.ts").collect::<Vec<_>>(),
+        vec!["src/polyfill.ts", "src/app.ts"]
+    );
+    assert_eq!(
+        graph.evaluation_order_from(["src/index.ts"]),
+        vec!["src/polyfill.ts", "src/app.ts", "src/index.ts"]
+    );
+}
+
+#[test]
+fn report_unresolved_imports() {
+    let graph = build(&[(
+        "src/index.ts",
+        "const a = 1;\nimport { Button } from './ui';\nexport default Button;",
+    )]);
+
+    let diagnostics = graph.unresolved_imports();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "src/index.ts:2:1: module \"./ui\" is never registered"
+    );
+}
+
+#[test]
+fn report_cycles() {
+    let graph = build(&[
+        ("a.js", "import { b } from './b';\nexport const a = 1;"),
+        ("b.js", "import { c } from './c';\nexport const b = 1;"),
+        ("c.js", "import { a } from './a';\nexport const c = 1;"),
+    ]);
+
+    assert_eq!(graph.cycles(), vec![vec!["a.js", "b.js", "c.js", "a.js"]]);
+    assert_eq!(graph.evaluation_order(), vec!["c.js", "b.js", "a.js"]);
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::build;
+use global_esm::{hmr_update, HmrUpdate};
+
+const INDEX: &str = "import { App } from './app';\nimport.meta.hot.accept();\nApp();";
+const APP: &str = "import { Button } from './button';\nexport const App = () => Button;";
+const BUTTON: &str = "export const Button = 'button';";
+
+#[test]
+fn propagate_to_accepting_importers() {
+    let graph = build(&[
+        ("src/index.ts", INDEX),
+        ("src/app.tsx", APP),
+        ("src/button.ts", BUTTON),
+    ]);
+
+    let update = hmr_update(
+        &graph,
+        "src/button.ts",
+        BUTTON.to_string(),
+        "export const Button = 'new button';".to_string(),
+    )
+    .unwrap();
+    assert_eq!(
+        update,
+        HmrUpdate::Update {
+            modules: vec![
+                "src/button.ts".to_string(),
+                "src/app.tsx".to_string(),
+                "src/index.ts".to_string(),
+            ]
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&update).unwrap(),
+        r#"{"type":"update","modules":["src/button.ts","src/app.tsx","src/index.ts"]}"#
+    );
+}
+
+#[test]
+fn accept_own_update_with_same_exports() {
+    let button = "export const Button = 'button';\nimport.meta.hot.accept();";
+    let graph = build(&[
+        ("src/index.ts", INDEX),
+        ("src/app.tsx", APP),
+        ("src/button.ts", button),
+    ]);
+
+    let update = hmr_update(
+        &graph,
+        "src/button.ts",
+        button.to_string(),
+        button.replace("'button'", "'new button'"),
+    )
+    .unwrap();
+    assert_eq!(
+        update,
+        HmrUpdate::Update {
+            modules: vec!["src/button.ts".to_string()]
+        }
+    );
+
+    // Importers hold the bindings of the old exports.
+    let update = hmr_update(
+        &graph,
+        "src/button.ts",
+        button.to_string(),
+        format!("{button}\nexport const Icon = 'icon';"),
+    )
+    .unwrap();
+    assert_eq!(
+        update,
+        HmrUpdate::Update {
+            modules: vec![
+                "src/button.ts".to_string(),
+                "src/app.tsx".to_string(),
+                "src/index.ts".to_string(),
+            ]
+        }
+    );
+}
+
+#[test]
+fn full_reload_without_boundaries() {
+    let graph = build(&[
+        ("src/index.ts", "import { App } from './app';\nApp();"),
+        ("src/app.tsx", APP),
+        ("src/button.ts", BUTTON),
+    ]);
+
+    let update = hmr_update(
+        &graph,
+        "src/button.ts",
+        BUTTON.to_string(),
+        BUTTON.to_string(),
+    )
+    .unwrap();
+    assert_eq!(
+        update,
+        HmrUpdate::FullReload {
+            reason: "\"src/index.ts\" has no importers that accept the update".to_string()
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&update).unwrap(),
+        r#"{"type":"fullReload","reason":"\"src/index.ts\" has no importers that accept the update"}"#
+    );
+}
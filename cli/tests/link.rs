@@ -0,0 +1,90 @@
+mod common;
+
+use common::build;
+use global_esm::check_links;
+
+fn check(sources: &[(&str, &str)]) -> Vec<String> {
+    check_links(&build(sources))
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn report_missing_named_imports() {
+    let diagnostics = check(&[
+        (
+            "src/index.ts",
+            "import { Button } from './ui';\nimport { Buton } from './ui';\nexport { Buton, Button };",
+        ),
+        ("src/ui.ts", "export const Button = 1;"),
+    ]);
+
+    assert_eq!(
+        diagnostics,
+        vec!["src/index.ts:2:1: \"Buton\" is not exported by \"src/ui.ts\""]
+    );
+}
+
+#[test]
+fn follow_star_exports() {
+    let diagnostics = check(&[
+        (
+            "src/index.ts",
+            "import utils, { format, locale, parse } from './utils';\nexport { utils, format, locale, parse };",
+        ),
+        ("src/utils/index.ts", "export * from './format';"),
+        (
+            "src/utils/format.ts",
+            "export * from './locale';\nexport const format = 1;",
+        ),
+        (
+            "src/utils/locale.ts",
+            "export const locale = 1;\nexport default locale;",
+        ),
+    ]);
+
+    assert_eq!(
+        diagnostics,
+        vec![
+            "src/index.ts:1:1: \"src/utils/index.ts\" has no default export",
+            "src/index.ts:1:1: \"parse\" is not exported by \"src/utils/index.ts\"",
+        ]
+    );
+}
+
+#[test]
+fn report_missing_default_exports() {
+    let diagnostics = check(&[
+        (
+            "src/index.ts",
+            "import App from './app';\nimport data from './data.cjs';\nexport { default as Root } from './app';\nexport { App, data };",
+        ),
+        ("src/app.tsx", "export const App = 1;"),
+        ("src/data.cjs", "module.exports = {};"),
+    ]);
+
+    assert_eq!(
+        diagnostics,
+        vec![
+            "src/index.ts:1:1: \"src/app.tsx\" has no default export",
+            "src/index.ts:3:1: \"src/app.tsx\" has no default export",
+        ]
+    );
+}
+
+#[test]
+fn skip_type_only_imports() {
+    let diagnostics = check(&[
+        (
+            "src/index.ts",
+            "import { Props } from './types';\nimport type { State } from './types';\nexport const props: Props & State = {};",
+        ),
+        (
+            "src/types.ts",
+            "export interface Props {}\nexport type State = {};",
+        ),
+    ]);
+
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+}
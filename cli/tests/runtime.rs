@@ -0,0 +1,61 @@
+use swc_core::{
+    common::{sync::Lrc, FileName, Globals, Mark, SourceMap, GLOBALS},
+    ecma::{
+        ast::*,
+        codegen::{text_writer::JsWriter, Emitter},
+        parser::{parse_file_as_module, Syntax, TsConfig},
+        transforms::{base::resolver, typescript::strip},
+        utils::DropSpan,
+        visit::{FoldWith, VisitMutWith},
+    },
+};
+use swc_global_esm::{generate_runtime, GlobalEsmConfig, Interop};
+
+/// Returns the statements of the source without types and comments.
+fn normalize(source: &str, syntax: Syntax) -> String {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon, source.to_string());
+    let module = parse_file_as_module(&fm, syntax, EsVersion::latest(), None, &mut Vec::new())
+        .expect("failed to parse runtime");
+
+    let mut module = GLOBALS.set(&Globals::new(), || {
+        let top_level_mark = Mark::new();
+        Program::Module(module)
+            .fold_with(&mut resolver(Mark::new(), top_level_mark, true))
+            .fold_with(&mut strip(top_level_mark))
+            .expect_module()
+    });
+    // Drop the `export {}` left by the type exports.
+    module.body.retain(ModuleItem::is_stmt);
+    module.visit_mut_with(&mut DropSpan {
+        preserve_ctxt: false,
+    });
+
+    let mut code = Vec::new();
+    Emitter {
+        cfg: Default::default(),
+        cm: cm.clone(),
+        comments: None,
+        wr: JsWriter::new(cm, "\n", &mut code, None),
+    }
+    .emit_module(&module)
+    .unwrap();
+    String::from_utf8(code).unwrap()
+}
+
+#[test]
+fn runtime_source_matches_generated_runtime() {
+    let runtime_source = include_str!("../../runtime/index.ts");
+    let generated = generate_runtime(&GlobalEsmConfig {
+        async_mode: true,
+        interop: Interop::Babel,
+        hmr: true,
+        ..Default::default()
+    });
+
+    assert_eq!(
+        normalize(runtime_source, Syntax::Typescript(TsConfig::default())),
+        normalize(&generated, Syntax::Es(Default::default())),
+        "`runtime/index.ts` and `generate_runtime` have diverged"
+    );
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use std::path::Path;
+
+use common::runtime_config;
+use global_esm::{module_name, transform};
+
+#[test]
+fn transform_typescript_file() {
+    let output = transform(
+        Path::new("src/app.ts"),
+        "src/app.ts",
+        String::from(
+            r#"import type { Props } from './types';
+import { format } from './format';
+export const render = (props: Props): string => format(props);
+"#,
+        ),
+        &runtime_config(),
+    )
+    .unwrap();
+
+    assert!(!output.code.contains("./types"));
+    assert!(output
+        .code
+        .contains(r#"global.__modules.import("./format")"#));
+    assert!(output
+        .code
+        .contains(r#"global.__modules.init("src/app.ts")"#));
+    assert!(output.map.contains(r#""sources":["src/app.ts"]"#));
+}
+
+#[test]
+fn report_parse_errors() {
+    let diagnostics = transform(
+        Path::new("src/broken.js"),
+        "src/broken.js",
+        String::from("const a = 1;\nexport const = 2;\n"),
+        &runtime_config(),
+    )
+    .unwrap_err();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        (diagnostics[0].line, diagnostics[0].column),
+        (2, 14),
+        "{}",
+        diagnostics[0]
+    );
+}
+
+#[test]
+fn module_name_is_relative_to_root() {
+    assert_eq!(
+        module_name(Path::new("/project/src/app.tsx"), Path::new("/project")),
+        "src/app.tsx"
+    );
+    assert_eq!(
+        module_name(Path::new("./src/app.tsx"), Path::new("/project")),
+        "src/app.tsx"
+    );
+}
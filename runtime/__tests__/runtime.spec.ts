@@ -225,6 +225,23 @@ describe('swc-plugin-global-esm/runtime', () => {
       });
     });
 
+    describe('when call `export()` with the same member more than once', () => {
+      let exportKey: string;
+      let exportValue: string;
+
+      beforeEach(() => {
+        exportKey = faker.string.alpha(10);
+        exportValue = faker.string.uuid();
+        global.__modules.init(modulePath);
+        global.__modules.export(modulePath, { [exportKey]: faker.string.uuid() });
+      });
+
+      it('should override the exported member', () => {
+        expect(() => global.__modules.export(modulePath, { [exportKey]: exportValue })).not.toThrow();
+        expect(global.__modules.import(modulePath)[exportKey]).toEqual(exportValue);
+      });
+    });
+
     describe('when call `export()` with valid exports object that has `default` property', () => {
       let exportValue: string;
       let exports: Record<string, unknown>;
@@ -283,4 +300,215 @@ describe('swc-plugin-global-esm/runtime', () => {
       });
     });
   });
+
+  describe('star exports', () => {
+    let modulePath: string;
+
+    beforeEach(() => {
+      modulePath = generateModulePath();
+      global.__modules.init(modulePath);
+    });
+
+    describe('when call `exportAll()` with namespaces that export the same name', () => {
+      let sharedValue: string;
+
+      beforeEach(() => {
+        sharedValue = faker.string.uuid();
+        global.__modules.export(modulePath, { local: 'local' });
+        global.__modules.exportAll(modulePath, [
+          { a: 'a', ambiguous: faker.string.uuid(), shared: sharedValue, local: 'a' },
+          { b: 'b', ambiguous: faker.string.uuid(), shared: sharedValue, default: 'b' },
+        ]);
+      });
+
+      it('should apply ESM star export rules', () => {
+        const exportedModule = global.__modules.import(modulePath);
+        expect(exportedModule.a).toEqual('a');
+        expect(exportedModule.b).toEqual('b');
+        expect(exportedModule.shared).toEqual(sharedValue);
+        expect(exportedModule.local).toEqual('local');
+        expect(exportedModule.default).toBeUndefined();
+        expect('ambiguous' in exportedModule).toEqual(false);
+      });
+    });
+
+    describe('when call `exportAll()` with `warnAmbiguous` flag', () => {
+      it('should warn ambiguous names', () => {
+        const warn = jest.spyOn(console, 'warn').mockImplementation(() => {});
+        global.__modules.exportAll(modulePath, [{ ambiguous: 'a' }, { ambiguous: 'b' }], true);
+        expect(warn).toHaveBeenCalledTimes(1);
+        warn.mockRestore();
+      });
+    });
+  });
+
+  describe('async modules', () => {
+    let modulePath: string;
+    let dependencyPath: string;
+
+    beforeEach(() => {
+      modulePath = generateModulePath();
+      dependencyPath = generateModulePath();
+    });
+
+    describe('when call `asyncModule()` with async dependencies', () => {
+      it('should run the factory after dependencies are ready', async () => {
+        const exportValue = faker.string.uuid();
+        const dependency = global.__modules.asyncModule(dependencyPath, [], async () => {
+          await Promise.resolve();
+          global.__modules.init(dependencyPath);
+          global.__modules.export(dependencyPath, { value: exportValue });
+        });
+        const module = global.__modules.asyncModule(modulePath, [dependencyPath], async () => {
+          const { value } = global.__modules.import(dependencyPath);
+          global.__modules.init(modulePath);
+          global.__modules.export(modulePath, { value });
+        });
+
+        await Promise.all([dependency, module]);
+        expect(global.__modules.import(modulePath).value).toEqual(exportValue);
+      });
+    });
+  });
+
+  describe('import attributes', () => {
+    let modulePath: string;
+
+    beforeEach(() => {
+      modulePath = generateModulePath();
+    });
+
+    describe('when a loader is registered for the `type` attribute', () => {
+      it('should load the module with the loader', () => {
+        const jsonModule = { default: { value: faker.string.uuid() } };
+        const loader = jest.fn(() => jsonModule);
+        global.__modules.registerLoader('json', loader);
+
+        expect(global.__modules.import(modulePath, { with: { type: 'json' } })).toBe(jsonModule);
+        expect(loader).toHaveBeenCalledWith(modulePath, { type: 'json' });
+      });
+    });
+
+    describe('when no loader is registered for the `type` attribute', () => {
+      it('should import the registered module', () => {
+        global.__modules.init(modulePath);
+        expect(() => global.__modules.import(modulePath, { with: { type: 'css' } })).not.toThrow();
+      });
+    });
+  });
+
+  describe('interop modules', () => {
+    let modulePath: string;
+    let exportValue: string;
+
+    beforeEach(() => {
+      modulePath = generateModulePath();
+      exportValue = faker.string.uuid();
+    });
+
+    describe('when the module is initialized as ES module', () => {
+      beforeEach(() => {
+        global.__modules.init(modulePath);
+        global.__modules.export(modulePath, { default: exportValue });
+      });
+
+      it('should use the module as it is with babel interop', () => {
+        const module = global.__modules.import(modulePath);
+        expect(global.__modules.interopDefault(module).default).toEqual(exportValue);
+        expect(global.__modules.interopWildcard(module)).toBe(module);
+      });
+
+      it('should wrap the module with node interop', () => {
+        const module = global.__modules.import(modulePath);
+        expect(global.__modules.interopDefault(module, true).default).toBe(module);
+        expect(global.__modules.interopWildcard(module, true).default).toBe(module);
+      });
+    });
+
+    describe('when the module is initialized as CommonJS module', () => {
+      beforeEach(() => {
+        global.__modules.init(modulePath, true);
+        global.__modules.export(modulePath, { value: exportValue });
+      });
+
+      it('should wrap the module with babel interop', () => {
+        const module = global.__modules.import(modulePath);
+        const namespace = global.__modules.interopWildcard(module);
+        expect(global.__modules.interopDefault(module).default).toBe(module);
+        expect(namespace.default).toBe(module);
+        expect(namespace.value).toEqual(exportValue);
+      });
+    });
+  });
+
+  describe('CommonJS module exports', () => {
+    let modulePath: string;
+
+    beforeEach(() => {
+      modulePath = generateModulePath();
+      global.__modules.init(modulePath, true);
+    });
+
+    describe('when call `exportModule()` with a function', () => {
+      it('should register the function as the module', () => {
+        const App = () => faker.string.uuid();
+        expect(global.__modules.exportModule(modulePath, App)).toBe(App);
+        global.__modules.export(modulePath, { displayName: 'App' });
+
+        const module = global.__modules.import(modulePath);
+        expect(module).toBe(App);
+        expect(module.displayName).toEqual('App');
+        expect(global.__modules.interopDefault(module).default).toBe(App);
+      });
+    });
+
+    describe('when call `exportModule()` with a primitive value', () => {
+      it('should register the value as the module', () => {
+        global.__modules.exportModule(modulePath, 0);
+
+        const module = global.__modules.import(modulePath);
+        expect(module).toEqual(0);
+        expect(global.__modules.interopDefault(module).default).toEqual(0);
+        expect(() => global.__modules.export(modulePath, { value: 1 })).toThrow(Error);
+      });
+    });
+
+    describe('when call `exportModule()` before `init()`', () => {
+      it('should throw error', () => {
+        expect(() => global.__modules.exportModule(generateModulePath(), 42)).toThrow(Error);
+      });
+    });
+  });
+
+  describe('hot modules', () => {
+    let modulePath: string;
+
+    beforeEach(() => {
+      modulePath = generateModulePath();
+    });
+
+    describe('when the module is evaluated again', () => {
+      it('should dispose the previous context and accept the new exports', async () => {
+        const exportValue = faker.string.uuid();
+        const dispose = jest.fn((data) => {
+          data.value = 'previous';
+        });
+        const accept = jest.fn();
+
+        const previous = global.__modules.hot(modulePath);
+        previous.dispose(dispose);
+        previous.accept(accept);
+        global.__modules.init(modulePath);
+
+        const hot = global.__modules.hot(modulePath);
+        global.__modules.init(modulePath);
+        global.__modules.export(modulePath, { value: exportValue });
+
+        expect(dispose).toHaveBeenCalledTimes(1);
+        expect(hot.data.value).toEqual('previous');
+        await Promise.resolve();
+        expect(accept).toHaveBeenCalledWith(global.__modules.import(modulePath));
+      });
+    });
+  });
 });
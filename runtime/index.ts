@@ -1,6 +1,30 @@
 
 type Modules<ModuleName extends string = string> = Record<ModuleName, ModuleExports | undefined>;
 type ModuleExports<ExportMember extends string = string> = Record<ExportMember, unknown>;
+type ImportAttributes = Record<string, string>;
+type ImportOptions = { with?: ImportAttributes };
+type ModuleLoader = (moduleName: string, attributes: ImportAttributes) => ModuleExports;
+type HotData = Record<string, unknown>;
+type HotModule = {
+  data: HotData;
+  acceptCallbacks: ((module: ModuleExports | undefined) => void)[];
+  disposeCallbacks: ((data: HotData) => void)[];
+};
+
+export interface HotContext {
+  /**
+   * Data passed from the dispose callbacks of the previous evaluation.
+   */
+  data: HotData;
+  /**
+   * Accept the updates of the module. `callback` is called with the new exports.
+   */
+  accept(callback?: (module: ModuleExports | undefined) => void): void;
+  /**
+   * Clean up side effects before the module is evaluated again.
+   */
+  dispose(callback: (data: HotData) => void): void;
+}
 
 export interface GlobalEsModule {
   /**
@@ -9,79 +33,174 @@ export interface GlobalEsModule {
   reset(moduleName?: string): void;
   /**
    * Initialize module before exports.
+   *
+   * Modules are marked as ES module (`__esModule`) unless `cjs` is `true`.
    */
-  init(moduleName: string): void;
+  init(moduleName: string, cjs?: boolean): void;
+  /**
+   * Register an async module that runs `factory` after its dependencies are ready (`asyncMode`).
+   *
+   * Dependencies that are not registered yet (or registered without `asyncModule`) are treated as ready,
+   * so register the modules in dependency order.
+   */
+  asyncModule(moduleName: string, dependencies: string[], factory: () => Promise<void>): Promise<void>;
+  /**
+   * Register a loader for modules imported with `type` attribute (eg. `with { type: 'json' }`).
+   */
+  registerLoader(type: string, loader: ModuleLoader): void;
   /**
    * Import an exported module in global ESM context.
+   *
+   * Modules imported with attributes are loaded by the loader of its `type` if registered.
    */
-  import(moduleName: string): ModuleExports;
+  import(moduleName: string, options?: ImportOptions): ModuleExports;
   /**
    * Import with wildcard an exported module in global ESM context.
    */
-  importWildcard(moduleName: string): ModuleExports;
+  importWildcard(moduleName: string, options?: ImportOptions): ModuleExports;
+  /**
+   * Returns the module that has `default` property (`interop: 'babel' | 'node'`).
+   *
+   * Same as `_interop_require_default` of `@swc/helpers`.
+   */
+  interopDefault(module: ModuleExports, nodeInterop?: boolean): ModuleExports;
+  /**
+   * Returns the namespace object of the module (`interop: 'babel' | 'node'`).
+   *
+   * Same as `_interop_require_wildcard` of `@swc/helpers`.
+   */
+  interopWildcard(module: ModuleExports, nodeInterop?: boolean): ModuleExports;
   /**
    * Export a module to global ESM context.
    */
   export(moduleName: string, exports: ModuleExports): void;
   /**
-   * Export all(*) module to global ESM context.
+   * Export all(*) modules to global ESM context.
+   *
+   * Follows ESM rules: local exports win, `default` is never exported
+   * and ambiguous names (exported by more than one module) are excluded.
    */
-  exportAll(moduleName: string, exports: ModuleExports): void;
+  exportAll(moduleName: string, exports: ModuleExports | ModuleExports[], warnAmbiguous?: boolean): void;
+  /**
+   * Export the value itself as the module (CommonJS `module.exports = value`).
+   *
+   * Returns the value as the result of the assignment.
+   */
+  exportModule<T>(moduleName: string, module: T): T;
+  /**
+   * Returns the hot context of the module (`import.meta.hot` with `hmr`).
+   *
+   * Modules call it once per evaluation, so the previous context of the module is disposed
+   * and accepts the update with the new exports.
+   */
+  hot(moduleName: string): HotContext;
 }
 
-((global, modules: Modules = {}) => {
-  if (typeof global === 'undefined') {
-    throw new Error('[Global ESM] `global` is undefined');
+// Keep in sync with `generate_runtime` of the transform crate (all features enabled).
+// `cli/tests/runtime.rs` fails when they diverge.
+((
+  globalObject,
+  modules: Modules = {},
+  loaders: Record<string, ModuleLoader | undefined> = {},
+  asyncModules: Record<string, Promise<void> | undefined> = {},
+  hotModules: Record<string, HotModule | undefined> = {},
+) => {
+  if (typeof globalObject === 'undefined') {
+    throw new Error('[Global ESM] global object is undefined');
   }
 
-  function getModule(moduleName: string) {
-    return modules[moduleName] || (() => {
+  function getModule(moduleName: string, options?: ImportOptions) {
+    const attributes = options && options.with;
+    const loader = attributes && attributes.type ? loaders[attributes.type] : undefined;
+    if (attributes && loader) {
+      return loader(moduleName, attributes);
+    }
+
+    // CommonJS modules may export falsy values (eg. `module.exports = 0`).
+    const module = modules[moduleName];
+    if (module === undefined) {
       throw new Error(`[Global ESM] "${moduleName}" module not found`);
-    })();
+    }
+    return module;
   }
 
-  function assertExports(moduleName: string, exports: unknown) {
-    if (typeof modules[moduleName] !== 'object') {
+  function assertInitialized(moduleName: string) {
+    if (modules[moduleName] === undefined) {
       throw new Error(`[Global ESM] "${moduleName}" module not initialized`);
     }
+  }
+
+  function assertExports(moduleName: string, exports: unknown) {
+    assertInitialized(moduleName);
+
+    const module = modules[moduleName];
+    if (module === null || (typeof module !== 'object' && typeof module !== 'function')) {
+      throw new Error(`[Global ESM] "${moduleName}" module is not an object to export members`);
+    }
 
-    if (typeof exports !== 'object') {
+    if (exports === null || (typeof exports !== 'object' && typeof exports !== 'function')) {
       throw new Error(`[Global ESM] invalid exports argument on "${moduleName}" module registration`);
     }
   }
 
+  function copyMembers(module: ModuleExports, newModule: ModuleExports) {
+    Object.keys(module).forEach((moduleMember) => {
+      if (moduleMember !== 'default' && Object.prototype.hasOwnProperty.call(module, moduleMember)) {
+        const descriptor = Object.getOwnPropertyDescriptor(module, moduleMember);
+        if (descriptor) {
+          Object.defineProperty(newModule, moduleMember, descriptor);
+        } else {
+          newModule[moduleMember] = module[moduleMember];
+        }
+      }
+    });
+    return newModule;
+  }
+
   const globalEsmApi: GlobalEsModule = {
     reset(moduleName) {
       if (typeof moduleName === 'string') {
         modules[moduleName] = undefined;
+        asyncModules[moduleName] = undefined;
       } else {
         modules = {};
+        asyncModules = {};
+      }
+    },
+    init(moduleName, cjs) {
+      const module = Object.create(null);
+      if (!cjs) {
+        Object.defineProperty(module, '__esModule', { value: true });
       }
+      modules[moduleName] = module;
     },
-    init(moduleName) {
-      modules[moduleName] = Object.create(null);
+    asyncModule(moduleName, dependencies, factory) {
+      const asyncModule = Promise.all(dependencies.map((dependency) => asyncModules[dependency])).then(() => factory());
+      asyncModules[moduleName] = asyncModule;
+      return asyncModule;
     },
-    import(moduleName) {
-      return getModule(moduleName);
+    registerLoader(type, loader) {
+      loaders[type] = loader;
     },
-    importWildcard(moduleName) {
-      const module = getModule(moduleName);
-      const newModule = Object.create(null);
+    import(moduleName, options) {
+      return getModule(moduleName, options);
+    },
+    importWildcard(moduleName, options) {
+      return copyMembers(getModule(moduleName, options), Object.create(null));
+    },
+    interopDefault(module, nodeInterop) {
+      return !nodeInterop && module && module.__esModule ? module : { default: module };
+    },
+    interopWildcard(module, nodeInterop) {
+      if (!nodeInterop && module && module.__esModule) {
+        return module;
+      }
 
-      Object.keys(module).forEach((moduleMember) => {
-        if (moduleMember !== 'default' && Object.prototype.hasOwnProperty.call(module, moduleMember)) {
-          const descriptor = Object.getOwnPropertyDescriptor(module, moduleMember);
-          if (descriptor) {
-            Object.defineProperty(
-              newModule,
-              moduleMember,
-              descriptor
-            );
-          } else {
-            newModule[moduleMember] = module[moduleMember];
-          }
-        }
-      });
+      const newModule = Object.create(null);
+      if (module !== null && (typeof module === 'object' || typeof module === 'function')) {
+        copyMembers(module, newModule);
+      }
+      newModule.default = module;
       return newModule;
     },
     export(moduleName, exports) {
@@ -90,29 +209,89 @@ export interface GlobalEsModule {
         if (Object.prototype.hasOwnProperty.call(exports, exportMember)) {
           Object.defineProperty(modules[moduleName], exportMember, {
             enumerable: true,
+            // CommonJS modules may register the same member more than once.
+            configurable: true,
             get: () => exports[exportMember],
           });
         }
       });
     },
-    exportAll(moduleName, exports) {
-      assertExports(moduleName, exports);
-      Object.keys(exports).forEach((exportMember) => {
-        if (exportMember !== 'default' && Object.prototype.hasOwnProperty.call(exports, exportMember)) {
-          Object.defineProperty(modules[moduleName], exportMember, {
-            enumerable: true,
-            get: () => exports[exportMember],
-          });
+    exportAll(moduleName, exports, warnAmbiguous) {
+      const namespaces = Array.isArray(exports) ? exports : [exports];
+      namespaces.forEach((namespace) => assertExports(moduleName, namespace));
+
+      const module = modules[moduleName]!;
+      const localMembers = Object.keys(module);
+      const starExports = new Map<string, ModuleExports>();
+      const ambiguousMembers = new Set<string>();
+
+      namespaces.forEach((namespace) => {
+        Object.keys(namespace).forEach((exportMember) => {
+          if (
+            exportMember === 'default' ||
+            localMembers.includes(exportMember) ||
+            !Object.prototype.hasOwnProperty.call(namespace, exportMember)
+          ) {
+            return;
+          }
+
+          const exportedNamespace = starExports.get(exportMember);
+          if (exportedNamespace === undefined) {
+            starExports.set(exportMember, namespace);
+          } else if (exportedNamespace[exportMember] !== namespace[exportMember]) {
+            ambiguousMembers.add(exportMember);
+          }
+        });
+      });
+
+      starExports.forEach((namespace, exportMember) => {
+        if (ambiguousMembers.has(exportMember)) {
+          if (warnAmbiguous) {
+            console.warn(`[Global ESM] "${exportMember}" is ambiguous in star exports of "${moduleName}" module`);
+          }
+          return;
         }
+
+        Object.defineProperty(module, exportMember, {
+          enumerable: true,
+          configurable: true,
+          get: () => namespace[exportMember],
+        });
       });
     },
+    exportModule(moduleName, module) {
+      assertInitialized(moduleName);
+      modules[moduleName] = module as ModuleExports;
+      return module;
+    },
+    hot(moduleName) {
+      const previous = hotModules[moduleName];
+      const hotModule: HotModule = { data: {}, acceptCallbacks: [], disposeCallbacks: [] };
+      hotModules[moduleName] = hotModule;
+      if (previous) {
+        previous.disposeCallbacks.forEach((callback) => callback(hotModule.data));
+        queueMicrotask(() => {
+          previous.acceptCallbacks.forEach((callback) => callback(modules[moduleName]));
+        });
+      }
+
+      return {
+        data: hotModule.data,
+        accept(callback) {
+          hotModule.acceptCallbacks.push(callback || (() => {}));
+        },
+        dispose(callback) {
+          hotModule.disposeCallbacks.push(callback);
+        },
+      };
+    },
   };
 
-  Object.defineProperty(global, '__modules', { value: globalEsmApi });
+  Object.defineProperty(globalObject, '__modules', { value: globalEsmApi });
 
-  // Define `global` property to global object.
-  if (!('global' in global)) {
-    Object.defineProperty(global, 'global', { value: global });
+  // Define the global name to the global object.
+  if (!('global' in globalObject)) {
+    Object.defineProperty(globalObject, 'global', { value: globalObject });
   }
 })(
   typeof globalThis !== 'undefined'
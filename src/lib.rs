@@ -1,6 +1,3 @@
-use std::collections::HashMap;
-
-use serde::Deserialize;
 use swc_core::{
     ecma::{ast::Program, visit::FoldWith},
     plugin::{
@@ -8,18 +5,11 @@ use swc_core::{
         proxies::TransformPluginProgramMetadata,
     },
 };
-use swc_global_esm::global_esm;
-
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct GlobalEsmModuleOptions {
-    runtime_module: Option<bool>,
-    import_paths: Option<HashMap<String, String>>,
-}
+use swc_global_esm::{global_esm, GlobalEsmConfig};
 
 #[plugin_transform]
 pub fn global_esm_plugin(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
-    let config = serde_json::from_str::<GlobalEsmModuleOptions>(
+    let config = serde_json::from_str::<GlobalEsmConfig>(
         &metadata
             .get_transform_plugin_config()
             .expect("failed to get plugin config for swc-plugin-global-esm"),
@@ -30,7 +20,7 @@ pub fn global_esm_plugin(program: Program, metadata: TransformPluginProgramMetad
         metadata
             .get_context(&TransformPluginMetadataContextKind::Filename)
             .unwrap_or_default(),
-        config.runtime_module.unwrap_or(false),
-        config.import_paths,
+        config,
+        metadata.comments,
     ))
 }
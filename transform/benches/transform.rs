@@ -0,0 +1,86 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use swc_core::{
+    common::{comments::NoopComments, sync::Lrc, FileName, Globals, SourceMap, GLOBALS},
+    ecma::{ast::Module, visit::VisitMutWith},
+};
+use swc_ecma_parser::{parse_file_as_module, Syntax, TsConfig};
+use swc_global_esm::{global_esm, GlobalEsmConfig};
+
+/// Returns a large generated module that exports `count` declarations of each kind.
+fn generated_source(count: usize) -> String {
+    let body = (0..64)
+        .map(|index| {
+            format!("  const value_{index} = [{index}, '{index}', {{ index: {index} }}];\n")
+        })
+        .collect::<String>();
+
+    let mut source = String::from("import { helper } from './helper';\n");
+    (0..count).for_each(|index| {
+        source.push_str(&format!(
+            "export function fn_{index}() {{\n{body}  return helper(value_0);\n}}\n"
+        ));
+        source.push_str(&format!(
+            "export class Class_{index} {{\n  method() {{\n{body}  }}\n}}\n"
+        ));
+        source.push_str(&format!(
+            "export const data_{index} = {{ items: [{}] }};\n",
+            (0..64)
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    });
+    source.push_str(&format!("export default function () {{\n{body}}}\n"));
+    source
+}
+
+fn parse(source: String) -> Module {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon, source);
+    parse_file_as_module(
+        &fm,
+        Syntax::Typescript(TsConfig::default()),
+        Default::default(),
+        None,
+        &mut vec![],
+    )
+    .expect("failed to parse module")
+}
+
+fn bench_global_esm(c: &mut Criterion) {
+    let mut group = c.benchmark_group("global_esm");
+    for runtime_module in [true, false] {
+        for count in [100, 500] {
+            let module = parse(generated_source(count));
+            let config = GlobalEsmConfig {
+                runtime_module,
+                ..Default::default()
+            };
+            let id = format!(
+                "{}/{count}",
+                if runtime_module { "runtime" } else { "bundle" }
+            );
+
+            group.bench_function(BenchmarkId::from_parameter(id), |b| {
+                GLOBALS.set(&Globals::new(), || {
+                    b.iter_batched(
+                        || module.clone(),
+                        |mut module| {
+                            module.visit_mut_with(&mut global_esm(
+                                String::from("generated.js"),
+                                config.clone(),
+                                NoopComments,
+                            ));
+                            module
+                        },
+                        BatchSize::LargeInput,
+                    )
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_global_esm);
+criterion_main!(benches);
@@ -0,0 +1,299 @@
+use crate::{
+    constants::{
+        GLOBAL, MODULE, MODULE_ASYNC_METHOD_NAME, MODULE_EXPORT_ALL_METHOD_NAME,
+        MODULE_EXPORT_METHOD_NAME, MODULE_EXPORT_MODULE_METHOD_NAME, MODULE_HOT_METHOD_NAME,
+        MODULE_IMPORT_METHOD_NAME, MODULE_IMPORT_WILDCARD_METHOD_NAME, MODULE_INIT_METHOD_NAME,
+        MODULE_INTEROP_DEFAULT_METHOD_NAME, MODULE_INTEROP_WILDCARD_METHOD_NAME,
+        MODULE_RESET_METHOD_NAME,
+    },
+    utils::{global_module_api_call_expr, global_module_api_call_stmt, obj_lit, registry_expr},
+    GlobalEsmConfig,
+};
+use swc_core::{
+    common::{Span, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{private_ident, quote_ident, ExprFactory},
+        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+    },
+};
+
+/// Generates the runtime calls of the transformed module.
+///
+/// Every method has the default implementation that targets the `global.__modules` runtime,
+/// so other module registries only need to override the methods they differ in.
+pub trait RuntimeAdapter: Send + Sync {
+    /// Returns the module registry that the default methods call.
+    ///
+    /// eg. `global.__modules`
+    fn registry(&self) -> Expr {
+        registry_expr(GLOBAL, MODULE)
+    }
+
+    /// eg. `global.__modules.import(module_src)`
+    /// eg. `global.__modules.import(module_src, { with: { type: "json" } })`
+    fn import(&self, span: Span, module_src: &str, with: Option<&ObjectLit>) -> Expr {
+        global_module_api_call_expr(
+            self.registry(),
+            span,
+            MODULE_IMPORT_METHOD_NAME,
+            import_args(module_src, with),
+        )
+    }
+
+    /// eg. `global.__modules.importWildcard(module_src)`
+    fn import_wildcard(&self, span: Span, module_src: &str, with: Option<&ObjectLit>) -> Expr {
+        global_module_api_call_expr(
+            self.registry(),
+            span,
+            MODULE_IMPORT_WILDCARD_METHOD_NAME,
+            import_args(module_src, with),
+        )
+    }
+
+    /// eg. `global.__modules.interopDefault(module)`
+    fn interop_default(&self, span: Span, module: Expr, node_interop: bool) -> Expr {
+        global_module_api_call_expr(
+            self.registry(),
+            span,
+            MODULE_INTEROP_DEFAULT_METHOD_NAME,
+            interop_args(module, node_interop),
+        )
+    }
+
+    /// eg. `global.__modules.interopWildcard(module)`
+    fn interop_wildcard(&self, span: Span, module: Expr, node_interop: bool) -> Expr {
+        global_module_api_call_expr(
+            self.registry(),
+            span,
+            MODULE_INTEROP_WILDCARD_METHOD_NAME,
+            interop_args(module, node_interop),
+        )
+    }
+
+    /// eg. `global.__modules.init(module_name)`
+    fn init(&self, span: Span, module_name: &str, cjs: bool) -> Stmt {
+        let mut args = vec![module_name.as_arg()];
+        if cjs {
+            args.push(true.as_arg());
+        }
+        global_module_api_call_stmt(self.registry(), span, MODULE_INIT_METHOD_NAME, args)
+    }
+
+    /// eg. `global.__modules.reset(module_name)`
+    fn reset(&self, span: Span, module_name: &str) -> Stmt {
+        global_module_api_call_stmt(
+            self.registry(),
+            span,
+            MODULE_RESET_METHOD_NAME,
+            vec![module_name.as_arg()],
+        )
+    }
+
+    /// eg. `global.__modules.export(module_name, exports)`
+    ///
+    /// Returns an expression because CommonJS exports are converted in place.
+    fn export(&self, span: Span, module_name: &str, exports: Expr) -> Expr {
+        global_module_api_call_expr(
+            self.registry(),
+            span,
+            MODULE_EXPORT_METHOD_NAME,
+            vec![module_name.as_arg(), exports.as_arg()],
+        )
+    }
+
+    /// eg. `global.__modules.exportModule(module_name, module)`
+    ///
+    /// Replacement of CommonJS `module.exports = module` that registers the value itself.
+    fn export_module(&self, span: Span, module_name: &str, module: Expr) -> Expr {
+        global_module_api_call_expr(
+            self.registry(),
+            span,
+            MODULE_EXPORT_MODULE_METHOD_NAME,
+            vec![module_name.as_arg(), module.as_arg()],
+        )
+    }
+
+    /// eg. `global.__modules.exportAll(module_name, [ns_1, ns_2])`
+    fn export_all(
+        &self,
+        span: Span,
+        module_name: &str,
+        namespaces: ArrayLit,
+        warn_ambiguous: bool,
+    ) -> Stmt {
+        let mut args = vec![module_name.as_arg(), namespaces.as_arg()];
+        if warn_ambiguous {
+            args.push(true.as_arg());
+        }
+        global_module_api_call_stmt(self.registry(), span, MODULE_EXPORT_ALL_METHOD_NAME, args)
+    }
+
+    /// eg. `global.__modules.asyncModule(module_name, [deps], async () => { ... })`
+    fn async_module(
+        &self,
+        span: Span,
+        module_name: &str,
+        dependencies: ArrayLit,
+        factory: Expr,
+    ) -> Stmt {
+        global_module_api_call_stmt(
+            self.registry(),
+            span,
+            MODULE_ASYNC_METHOD_NAME,
+            vec![
+                module_name.as_arg(),
+                dependencies.as_arg(),
+                factory.as_arg(),
+            ],
+        )
+    }
+
+    /// eg. `global.__modules.hot(module_name)`
+    ///
+    /// Hot context of the module that replaces `import.meta.hot` (`hmr`).
+    /// It is called once at the top of the module.
+    fn hot(&self, span: Span, module_name: &str) -> Expr {
+        global_module_api_call_expr(
+            self.registry(),
+            span,
+            MODULE_HOT_METHOD_NAME,
+            vec![module_name.as_arg()],
+        )
+    }
+
+    /// Returns the replacement of `import(module_src)`.
+    ///
+    /// Dynamic imports are kept as it is when `None` is returned.
+    fn dynamic_import(&self, _span: Span, _module_src: &str) -> Option<Expr> {
+        None
+    }
+}
+
+/// The `global.__modules` runtime (`runtime/index.ts` or `generate_runtime`).
+///
+/// The global and registry names follow the config (`globalName`, `registryName`).
+#[derive(Debug, Clone)]
+pub struct GlobalModulesAdapter {
+    global_name: String,
+    registry_name: String,
+}
+
+impl GlobalModulesAdapter {
+    pub fn new(config: &GlobalEsmConfig) -> Self {
+        GlobalModulesAdapter {
+            global_name: config.global_name().to_string(),
+            registry_name: config.registry_name().to_string(),
+        }
+    }
+}
+
+impl Default for GlobalModulesAdapter {
+    fn default() -> Self {
+        GlobalModulesAdapter::new(&GlobalEsmConfig::default())
+    }
+}
+
+impl RuntimeAdapter for GlobalModulesAdapter {
+    fn registry(&self) -> Expr {
+        registry_expr(&self.global_name, &self.registry_name)
+    }
+}
+
+fn import_args(module_src: &str, with: Option<&ObjectLit>) -> Vec<ExprOrSpread> {
+    let mut args = vec![module_src.as_arg()];
+    if let Some(with) = with {
+        args.push(
+            obj_lit(
+                DUMMY_SP,
+                Some(vec![Prop::KeyValue(KeyValueProp {
+                    key: quote_ident!("with").into(),
+                    value: Box::new(Expr::Object(with.clone())),
+                })
+                .into()]),
+            )
+            .as_arg(),
+        );
+    }
+    args
+}
+
+fn interop_args(module: Expr, node_interop: bool) -> Vec<ExprOrSpread> {
+    let mut args = vec![module.as_arg()];
+    if node_interop {
+        args.push(true.as_arg());
+    }
+    args
+}
+
+/// Converts `import("module_src")` expressions with `RuntimeAdapter::dynamic_import`.
+pub(crate) struct DynamicImportConverter<'a> {
+    pub adapter: &'a dyn RuntimeAdapter,
+    pub config: &'a GlobalEsmConfig,
+}
+
+impl VisitMut for DynamicImportConverter<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        if let Expr::Call(CallExpr {
+            span,
+            callee: Callee::Import(_),
+            args,
+            ..
+        }) = expr
+        {
+            if let [ExprOrSpread {
+                spread: None,
+                expr: src,
+            }] = &args[..]
+            {
+                if let Expr::Lit(Lit::Str(Str { value, .. })) = &**src {
+                    let module_src = self.config.to_actual_path(value);
+                    if let Some(converted) = self.adapter.dynamic_import(*span, &module_src) {
+                        *expr = converted;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Converts `import.meta.hot` expressions to the hot context of the module.
+///
+/// The context is declared once per evaluation of the module (`hot_ident`).
+pub(crate) struct ImportMetaHotConverter {
+    pub hot_ident: Option<Ident>,
+}
+
+impl VisitMut for ImportMetaHotConverter {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        if let Expr::Member(MemberExpr {
+            span,
+            obj,
+            prop: MemberProp::Ident(prop),
+        }) = expr
+        {
+            if prop.sym == "hot"
+                && matches!(
+                    &**obj,
+                    Expr::MetaProp(MetaPropExpr {
+                        kind: MetaPropKind::ImportMeta,
+                        ..
+                    })
+                )
+            {
+                let hot_ident = self
+                    .hot_ident
+                    .get_or_insert_with(|| private_ident!(*span, "__hot"));
+                *expr = hot_ident.clone().into();
+            }
+        }
+    }
+}
@@ -0,0 +1,462 @@
+use std::collections::HashSet;
+
+use swc_core::{
+    common::Span,
+    ecma::{
+        ast::*,
+        atoms::JsWord,
+        utils::find_pat_ids,
+        visit::{noop_visit_type, Visit, VisitWith},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleType {
+    Default,
+    Named,
+    // `export { default as ... } from ...`
+    DefaultAsNamed,
+    // import: namespace, export: all
+    NamespaceOrAll,
+}
+
+/// An imported binding.
+///
+/// - `foo` in `import foo from 'src'`
+/// - `bar` in `import { baz as bar } from 'src'` (imported: `baz`)
+/// - `ns` in `import * as ns from 'src'`
+#[derive(Debug, Clone)]
+pub struct ImportInfo {
+    pub ident: Ident,
+    pub imported: Option<Ident>,
+    pub module_src: String,
+    pub module_type: ModuleType,
+    /// Import attributes (eg. `with { type: "json" }`).
+    pub with: Option<Box<ObjectLit>>,
+    pub span: Span,
+}
+
+/// An import without bindings that is evaluated for its side effects.
+///
+/// - `import 'src'`
+#[derive(Debug, Clone)]
+pub struct BareImportInfo {
+    pub module_src: String,
+    pub with: Option<Box<ObjectLit>>,
+    pub span: Span,
+}
+
+/// A binding exported from the module itself.
+///
+/// `ident` is `None` for anonymous default exports (eg. `export default 0`).
+/// `reassigned` is `true` when the binding is assigned anywhere after its declaration
+/// (eg. `export let count = 0; count++;`).
+#[derive(Debug, Clone)]
+pub struct ExportInfo {
+    pub ident: Option<Ident>,
+    pub name: JsWord,
+    pub module_type: ModuleType,
+    pub reassigned: bool,
+    pub span: Span,
+}
+
+/// A binding re-exported from another module.
+///
+/// - `export { a as b } from 'src'` (imported: `a`, name: `b`)
+/// - `export * as ns from 'src'` (imported: `None`, name: `ns`)
+#[derive(Debug, Clone)]
+pub struct ReExportInfo {
+    pub imported: Option<Ident>,
+    pub name: JsWord,
+    pub module_src: String,
+    pub module_type: ModuleType,
+    pub with: Option<Box<ObjectLit>>,
+    pub span: Span,
+}
+
+/// `export * from 'src'`
+#[derive(Debug, Clone)]
+pub struct StarExportInfo {
+    pub module_src: String,
+    pub module_type: ModuleType,
+    pub with: Option<Box<ObjectLit>>,
+    pub span: Span,
+}
+
+/// Imports and exports of an ES module, in source order.
+///
+/// Each entry holds the span of the import or export declaration it comes from.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleInfo {
+    pub imports: Vec<ImportInfo>,
+    pub bare_imports: Vec<BareImportInfo>,
+    pub exports: Vec<ExportInfo>,
+    pub re_exports: Vec<ReExportInfo>,
+    pub star_exports: Vec<StarExportInfo>,
+    /// `true` when the module uses top-level `await` (including `for await`).
+    pub has_top_level_await: bool,
+}
+
+impl ModuleInfo {
+    /// Returns the exported names including re-exports (star exports are excluded).
+    pub fn export_names(&self) -> Vec<&JsWord> {
+        self.exports
+            .iter()
+            .map(|export| &export.name)
+            .chain(self.re_exports.iter().map(|re_export| &re_export.name))
+            .collect()
+    }
+
+    /// Returns the unique module sources this module depends on, in source order.
+    pub fn module_sources(&self) -> Vec<&str> {
+        let mut sources: Vec<&str> = Vec::new();
+        self.imports
+            .iter()
+            .map(|import| import.module_src.as_str())
+            .chain(
+                self.bare_imports
+                    .iter()
+                    .map(|bare_import| bare_import.module_src.as_str()),
+            )
+            .chain(
+                self.re_exports
+                    .iter()
+                    .map(|re_export| re_export.module_src.as_str()),
+            )
+            .chain(
+                self.star_exports
+                    .iter()
+                    .map(|star_export| star_export.module_src.as_str()),
+            )
+            .for_each(|src| {
+                if !sources.contains(&src) {
+                    sources.push(src);
+                }
+            });
+        sources
+    }
+}
+
+/// Collects imports and exports of the module without modifying it.
+pub fn analyze_module(module: &Module) -> ModuleInfo {
+    let mut analyzer = ModuleAnalyzer::default();
+    module.visit_with(&mut analyzer);
+
+    let mut reassignment_collector = ReassignmentCollector::default();
+    module.visit_with(&mut reassignment_collector);
+
+    let mut top_level_await_finder = TopLevelAwaitFinder::default();
+    module.visit_with(&mut top_level_await_finder);
+
+    let mut module_info = analyzer.info;
+    module_info.has_top_level_await = top_level_await_finder.found;
+    module_info.exports.iter_mut().for_each(|export_info| {
+        export_info.reassigned = export_info
+            .ident
+            .as_ref()
+            .is_some_and(|ident| reassignment_collector.reassigned.contains(&ident.to_id()));
+    });
+    module_info
+}
+
+/// Returns the import or export name as an ident.
+///
+/// String names (eg. `'a-b'` in `export { a as 'a-b' }`) may not be valid identifiers,
+/// so they are only used as member keys.
+fn module_export_name_ident(module_export_name: &ModuleExportName) -> Ident {
+    match module_export_name {
+        ModuleExportName::Ident(ident) => ident.clone(),
+        ModuleExportName::Str(Str { value, span, .. }) => Ident::new(value.clone(), *span),
+    }
+}
+
+#[derive(Default)]
+struct ModuleAnalyzer {
+    info: ModuleInfo,
+}
+
+impl ModuleAnalyzer {
+    fn push_named_export(&mut self, ident: Ident, span: Span) {
+        self.info.exports.push(ExportInfo {
+            name: ident.sym.clone(),
+            ident: Some(ident),
+            module_type: ModuleType::Named,
+            reassigned: false,
+            span,
+        });
+    }
+
+    fn push_default_export(&mut self, ident: Option<Ident>, span: Span) {
+        self.info.exports.push(ExportInfo {
+            ident,
+            name: "default".into(),
+            module_type: ModuleType::Default,
+            reassigned: false,
+            span,
+        });
+    }
+}
+
+impl Visit for ModuleAnalyzer {
+    noop_visit_type!();
+
+    fn visit_module_items(&mut self, items: &[ModuleItem]) {
+        for item in items {
+            if let ModuleItem::ModuleDecl(module_decl) = item {
+                module_decl.visit_with(self);
+            }
+        }
+    }
+
+    fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
+        if import_decl.type_only {
+            return;
+        }
+
+        let module_src = import_decl.src.value.to_string();
+        let span = import_decl.span;
+        if import_decl.specifiers.is_empty() {
+            self.info.bare_imports.push(BareImportInfo {
+                module_src,
+                with: import_decl.with.clone(),
+                span,
+            });
+            return;
+        }
+
+        import_decl
+            .specifiers
+            .iter()
+            .for_each(|import_spec| match import_spec {
+                ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                    self.info.imports.push(ImportInfo {
+                        ident: local.clone(),
+                        imported: None,
+                        module_src: module_src.clone(),
+                        module_type: ModuleType::Default,
+                        with: import_decl.with.clone(),
+                        span,
+                    });
+                }
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    local,
+                    imported,
+                    is_type_only: false,
+                    ..
+                }) => {
+                    self.info.imports.push(ImportInfo {
+                        ident: local.clone(),
+                        imported: imported.as_ref().map(module_export_name_ident),
+                        module_src: module_src.clone(),
+                        module_type: ModuleType::Named,
+                        with: import_decl.with.clone(),
+                        span,
+                    });
+                }
+                ImportSpecifier::Named(_) => {}
+                ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                    self.info.imports.push(ImportInfo {
+                        ident: local.clone(),
+                        imported: None,
+                        module_src: module_src.clone(),
+                        module_type: ModuleType::NamespaceOrAll,
+                        with: import_decl.with.clone(),
+                        span,
+                    });
+                }
+            });
+    }
+
+    fn visit_export_decl(&mut self, export_decl: &ExportDecl) {
+        match &export_decl.decl {
+            Decl::Var(var_decl) => find_pat_ids::<_, Ident>(&var_decl.decls)
+                .into_iter()
+                .for_each(|ident| self.push_named_export(ident, export_decl.span)),
+            Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+                self.push_named_export(ident.clone(), export_decl.span)
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_export_default_decl(&mut self, export_default_decl: &ExportDefaultDecl) {
+        match &export_default_decl.decl {
+            DefaultDecl::Class(ClassExpr { ident, .. }) | DefaultDecl::Fn(FnExpr { ident, .. }) => {
+                self.push_default_export(ident.clone(), export_default_decl.span)
+            }
+            DefaultDecl::TsInterfaceDecl(_) => {}
+        }
+    }
+
+    fn visit_export_default_expr(&mut self, export_default_expr: &ExportDefaultExpr) {
+        self.push_default_export(None, export_default_expr.span);
+    }
+
+    fn visit_named_export(&mut self, named_export: &NamedExport) {
+        if named_export.type_only {
+            return;
+        }
+
+        match &named_export.src {
+            // `export { a, b as c };`
+            None => named_export.specifiers.iter().for_each(|export_spec| {
+                if let ExportSpecifier::Named(ExportNamedSpecifier {
+                    orig: ModuleExportName::Ident(orig_ident),
+                    exported,
+                    is_type_only: false,
+                    ..
+                }) = export_spec
+                {
+                    let name = exported
+                        .as_ref()
+                        .map_or(orig_ident.sym.clone(), |exported| {
+                            module_export_name_ident(exported).sym
+                        });
+                    self.info.exports.push(ExportInfo {
+                        ident: Some(orig_ident.clone()),
+                        name,
+                        module_type: ModuleType::Named,
+                        reassigned: false,
+                        span: named_export.span,
+                    });
+                }
+            }),
+            // `export * as ns from '...';`
+            // `export { a, default as b } from '...';`
+            Some(module_src) => {
+                named_export
+                    .specifiers
+                    .iter()
+                    .for_each(|export_spec| match export_spec {
+                        ExportSpecifier::Namespace(ExportNamespaceSpecifier { name, .. }) => {
+                            self.info.re_exports.push(ReExportInfo {
+                                imported: None,
+                                name: module_export_name_ident(name).sym,
+                                module_src: module_src.value.to_string(),
+                                module_type: ModuleType::NamespaceOrAll,
+                                with: named_export.with.clone(),
+                                span: named_export.span,
+                            })
+                        }
+                        ExportSpecifier::Named(ExportNamedSpecifier {
+                            orig,
+                            exported,
+                            is_type_only: false,
+                            ..
+                        }) => {
+                            let orig_ident = module_export_name_ident(orig);
+                            let name = exported
+                                .as_ref()
+                                .map_or(orig_ident.sym.clone(), |exported| {
+                                    module_export_name_ident(exported).sym
+                                });
+                            self.info.re_exports.push(ReExportInfo {
+                                imported: Some(orig_ident.clone()),
+                                name,
+                                module_src: module_src.value.to_string(),
+                                module_type: if orig_ident.sym == "default" {
+                                    ModuleType::DefaultAsNamed
+                                } else {
+                                    ModuleType::Named
+                                },
+                                with: named_export.with.clone(),
+                                span: named_export.span,
+                            });
+                        }
+                        _ => {}
+                    })
+            }
+        }
+    }
+
+    fn visit_export_all(&mut self, export_all: &ExportAll) {
+        if export_all.type_only {
+            return;
+        }
+
+        self.info.star_exports.push(StarExportInfo {
+            module_src: export_all.src.value.to_string(),
+            module_type: ModuleType::NamespaceOrAll,
+            with: export_all.with.clone(),
+            span: export_all.span,
+        });
+    }
+}
+
+/// Collects bindings that are targets of assignments, updates or `for-in/of` heads.
+#[derive(Default)]
+struct ReassignmentCollector {
+    reassigned: HashSet<Id>,
+}
+
+impl ReassignmentCollector {
+    fn insert_pat(&mut self, pat: &Pat) {
+        match pat {
+            Pat::Expr(expr) => self.insert_expr(expr),
+            _ => self.reassigned.extend(find_pat_ids::<_, Id>(pat)),
+        }
+    }
+
+    fn insert_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Ident(ident) => {
+                self.reassigned.insert(ident.to_id());
+            }
+            Expr::Paren(ParenExpr { expr, .. }) => self.insert_expr(expr),
+            _ => {}
+        }
+    }
+}
+
+impl Visit for ReassignmentCollector {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+        match &assign_expr.left {
+            PatOrExpr::Pat(pat) => self.insert_pat(pat),
+            PatOrExpr::Expr(expr) => self.insert_expr(expr),
+        }
+        assign_expr.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, update_expr: &UpdateExpr) {
+        self.insert_expr(&update_expr.arg);
+        update_expr.visit_children_with(self);
+    }
+
+    fn visit_for_head(&mut self, for_head: &ForHead) {
+        if let ForHead::Pat(pat) = for_head {
+            self.insert_pat(pat);
+        }
+        for_head.visit_children_with(self);
+    }
+}
+
+/// Finds `await` expressions and `for await` statements outside of functions.
+#[derive(Default)]
+struct TopLevelAwaitFinder {
+    found: bool,
+}
+
+impl Visit for TopLevelAwaitFinder {
+    noop_visit_type!();
+
+    fn visit_await_expr(&mut self, _: &AwaitExpr) {
+        self.found = true;
+    }
+
+    fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt) {
+        self.found |= for_of_stmt.is_await;
+        for_of_stmt.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_constructor(&mut self, _: &Constructor) {}
+
+    fn visit_getter_prop(&mut self, _: &GetterProp) {}
+
+    fn visit_setter_prop(&mut self, _: &SetterProp) {}
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{GLOBAL, JSX_PRAGMA, MODULE};
+
+/// Options of the `global_esm` transform (plugin config).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GlobalEsmConfig {
+    /// Convert import statements to custom module system and remove export statements.
+    pub runtime_module: bool,
+    /// Actual module path aliases (resolved module path).
+    pub import_paths: Option<HashMap<String, String>>,
+    /// Convert CommonJS `require()` calls and `module.exports` assignments to the global module API.
+    ///
+    /// Only applied when `runtime_module` is `true`.
+    pub cjs: bool,
+    /// Interop mode of default and namespace imports in runtime module.
+    pub interop: Interop,
+    /// Warn ambiguous names of star re-exports (`export * from ...`) at runtime.
+    pub warn_ambiguous_exports: bool,
+    /// Wrap modules that use top-level `await` or have imports with an async factory
+    /// that runs after its dependencies are ready.
+    ///
+    /// Dependencies must be registered before their importers (eg. in dependency order of a bundle),
+    /// since dependencies that are not registered yet are treated as ready.
+    ///
+    /// Only applied when `runtime_module` is `true`.
+    pub async_mode: bool,
+    /// Output format of the runtime module.
+    ///
+    /// Only applied when `runtime_module` is `true`.
+    pub target: Target,
+    /// Wrap the module body to isolate its top-level declarations (eg. concatenated bundles).
+    ///
+    /// Only applied when `runtime_module` is `true` and `target` is `global`.
+    pub wrap: Wrap,
+    /// Remove imports whose bindings are never referenced in the module.
+    ///
+    /// Only applied when `runtime_module` is `true` and `target` is `global`.
+    pub remove_unused_imports: bool,
+    /// Pragma of the classic JSX runtime (defaults to `React.createElement`).
+    ///
+    /// Its root binding (eg. `React`) is used by JSX elements and fragments when
+    /// `remove_unused_imports` is `true`.
+    pub jsx_pragma: Option<String>,
+    /// Module sources (or actual module paths) that have side effects.
+    ///
+    /// Bare imports of the modules (eg. `import './polyfill'`) are converted to import calls
+    /// and the module is still imported without bindings when all of its imports are removed.
+    /// Bare imports of other modules are removed in runtime mode.
+    pub side_effects: Vec<String>,
+    /// Name of the global object that holds the module registry (defaults to `global`).
+    pub global_name: Option<String>,
+    /// Name of the module registry on the global object (defaults to `__modules`).
+    pub registry_name: Option<String>,
+    /// Convert `import.meta.hot` to the hot context of the module
+    /// (`global.__modules.hot(module_name)`).
+    ///
+    /// Only applied when `runtime_module` is `true` and `target` is `global`.
+    pub hmr: bool,
+}
+
+/// Wrapper of the runtime module body.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Wrap {
+    /// Keep the module body at the top level.
+    #[default]
+    None,
+    /// Wrap with an IIFE (`(() => { ... })();`).
+    ///
+    /// Modules that use top-level `await` are wrapped with a block instead.
+    Iife,
+    /// Wrap with a block (`{ ... }`).
+    Block,
+}
+
+/// Output format of the runtime module.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Target {
+    /// Register modules to `global.__modules`.
+    #[default]
+    Global,
+    /// Register modules with `System.register` (SystemJS).
+    #[serde(rename = "systemjs")]
+    SystemJs,
+}
+
+/// Interop mode of default and namespace imports (same as swc's `common_js` module).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Interop {
+    /// Use the registered module as it is (`__mod.default`).
+    #[default]
+    None,
+    /// Treat the module as ES module only when it is marked as `__esModule`.
+    Babel,
+    /// Always treat the module as CommonJS (default import is the module itself).
+    Node,
+}
+
+impl GlobalEsmConfig {
+    /// Check the module is marked as side-effectful.
+    pub(crate) fn has_side_effects(&self, module_src: &str) -> bool {
+        let actual_path = self.to_actual_path(module_src);
+        self.side_effects
+            .iter()
+            .any(|src| src == module_src || *src == actual_path)
+    }
+
+    /// Returns the name of the global object (eg. `global`).
+    pub fn global_name(&self) -> &str {
+        self.global_name.as_deref().unwrap_or(GLOBAL)
+    }
+
+    /// Returns the name of the module registry (eg. `__modules`).
+    pub fn registry_name(&self) -> &str {
+        self.registry_name.as_deref().unwrap_or(MODULE)
+    }
+
+    /// Returns the root binding name of the JSX pragma (eg. `React` of `React.createElement`).
+    pub fn jsx_pragma_root(&self) -> &str {
+        let pragma = self.jsx_pragma.as_deref().unwrap_or(JSX_PRAGMA);
+        pragma.split('.').next().unwrap_or(pragma)
+    }
+
+    /// Find actual module path from `import_paths`
+    pub fn to_actual_path(&self, module_src: &str) -> String {
+        self.import_paths
+            .as_ref()
+            .and_then(|import_paths| import_paths.get(module_src))
+            .cloned()
+            .unwrap_or_else(|| module_src.to_string())
+    }
+}
@@ -6,3 +6,12 @@ pub const MODULE_IMPORT_METHOD_NAME: &str = "import";
 pub const MODULE_IMPORT_WILDCARD_METHOD_NAME: &str = "importWildcard";
 pub const MODULE_EXPORT_METHOD_NAME: &str = "export";
 pub const MODULE_EXPORT_ALL_METHOD_NAME: &str = "exportAll";
+pub const MODULE_EXPORT_MODULE_METHOD_NAME: &str = "exportModule";
+pub const MODULE_ASYNC_METHOD_NAME: &str = "asyncModule";
+pub const MODULE_INTEROP_DEFAULT_METHOD_NAME: &str = "interopDefault";
+pub const MODULE_INTEROP_WILDCARD_METHOD_NAME: &str = "interopWildcard";
+pub const MODULE_REGISTER_LOADER_METHOD_NAME: &str = "registerLoader";
+pub const MODULE_HOT_METHOD_NAME: &str = "hot";
+pub const IGNORE_FILE_PRAGMA: &str = "@global-esm-ignore";
+pub const KEEP_PRAGMA: &str = "@global-esm-keep";
+pub const JSX_PRAGMA: &str = "React.createElement";
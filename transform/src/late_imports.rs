@@ -0,0 +1,132 @@
+use crate::{
+    analysis::analyze_module, constants::KEEP_PRAGMA, is_directive,
+    module_collector_esm::ImportModule, take_directives, utils::has_leading_pragma,
+    GlobalEsmConfig, GlobalEsmModule, Wrap,
+};
+use swc_core::{
+    common::{comments::Comments, util::take::Take},
+    ecma::{
+        ast::*,
+        visit::{noop_visit_mut_type, VisitMut},
+    },
+};
+
+/// Converts import statements that are injected after the `global_esm` transform.
+///
+/// eg. `@swc/helpers` imports (`externalHelpers: true`) and `react/jsx-runtime` (automatic JSX runtime).
+pub struct LateImports<C: Comments> {
+    module: GlobalEsmModule<C>,
+}
+
+impl<C: Comments> LateImports<C> {
+    pub fn new(config: GlobalEsmConfig, comments: C) -> Self {
+        LateImports {
+            module: GlobalEsmModule::default(
+                String::new(),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    ..config
+                },
+                comments,
+            ),
+        }
+    }
+
+    /// Check the import declaration has `/* @global-esm-keep */` comment.
+    fn is_kept(&self, import_decl: &ImportDecl) -> bool {
+        has_leading_pragma(&self.module.comments, import_decl.span.lo, KEEP_PRAGMA)
+    }
+}
+
+impl<C: Comments> VisitMut for LateImports<C> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        // Kept imports remain as ES module syntax.
+        let (import_items, items): (Vec<_>, Vec<_>) = std::mem::take(&mut module.body)
+            .into_iter()
+            .partition(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+                    !self.is_kept(import_decl)
+                }
+                _ => false,
+            });
+        module.body = items;
+
+        let imports = analyze_module(&Module {
+            body: import_items,
+            ..Module::dummy()
+        })
+        .imports
+        .into_iter()
+        .map(ImportModule::from)
+        .collect::<Vec<_>>();
+        let import_stmts = self.module.convert_esm_import(&imports);
+
+        match module_body_stmts(module, &self.module.config) {
+            Some(stmts) => {
+                // Keep the directives of the wrapper at the top.
+                let directive_count = stmts.iter().take_while(|stmt| is_directive(stmt)).count();
+                stmts.splice(
+                    directive_count..directive_count,
+                    import_stmts.into_iter().filter_map(|item| item.stmt()),
+                );
+            }
+            None => {
+                let directives = take_directives(module);
+                module.body.splice(..0, import_stmts);
+                module
+                    .body
+                    .splice(..0, directives.into_iter().map(ModuleItem::from));
+            }
+        }
+    }
+}
+
+/// Returns the statements of the wrapped module body.
+///
+/// - `(() => { ... })();` and `{ ... }` (`wrap`)
+/// - `global.__modules.asyncModule(module_name, deps, async () => { ... });` (`async_mode`)
+fn module_body_stmts<'a>(
+    module: &'a mut Module,
+    config: &GlobalEsmConfig,
+) -> Option<&'a mut Vec<Stmt>> {
+    if config.wrap == Wrap::None && !config.async_mode {
+        return None;
+    }
+
+    match module.body.last_mut()? {
+        ModuleItem::Stmt(Stmt::Block(block)) if config.wrap != Wrap::None => Some(&mut block.stmts),
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
+            let Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) = &mut **expr
+            else {
+                return None;
+            };
+            let factory = match args.last_mut() {
+                // Factory of the async module.
+                Some(ExprOrSpread { spread: None, expr }) if config.async_mode => &mut **expr,
+                // IIFE
+                None if config.wrap != Wrap::None => &mut **callee,
+                _ => return None,
+            };
+            let mut factory = factory;
+            while let Expr::Paren(ParenExpr { expr, .. }) = factory {
+                factory = &mut **expr;
+            }
+            match factory {
+                Expr::Arrow(ArrowExpr { params, body, .. }) if params.is_empty() => {
+                    match &mut **body {
+                        BlockStmtOrExpr::BlockStmt(block) => Some(&mut block.stmts),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
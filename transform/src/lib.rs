@@ -1,106 +1,166 @@
+mod adapter;
+pub mod analysis;
+mod config;
 mod constants;
+mod late_imports;
+mod module_collector_cjs;
 mod module_collector_esm;
+mod runtime;
+mod system;
 mod utils;
 
-use constants::{
-    GLOBAL, MODULE, MODULE_EXPORT_ALL_METHOD_NAME, MODULE_EXPORT_METHOD_NAME,
-    MODULE_IMPORT_METHOD_NAME, MODULE_IMPORT_WILDCARD_METHOD_NAME, MODULE_INIT_METHOD_NAME,
-    MODULE_RESET_METHOD_NAME,
-};
-use module_collector_esm::{EsModuleCollector, ExportModule, ImportModule, ModuleType};
-use regex::Regex;
-use std::collections::{BTreeMap, HashMap};
+pub use adapter::{GlobalModulesAdapter, RuntimeAdapter};
+pub use analysis::{analyze_module, ModuleInfo, ModuleType};
+pub use config::{GlobalEsmConfig, Interop, Target, Wrap};
+pub use runtime::generate_runtime;
+
+/// Version of the transform crate.
+///
+/// The output of the same source and config may change between versions (eg. cache keys).
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+use adapter::{DynamicImportConverter, ImportMetaHotConverter};
+use analysis::BareImportInfo;
+use constants::IGNORE_FILE_PRAGMA;
+use late_imports::LateImports;
+use module_collector_cjs::CjsModuleCollector;
+use module_collector_esm::{EsModuleCollector, ExportModule, ImportModule};
+use std::{collections::BTreeMap, sync::Arc};
 use swc_core::{
-    common::DUMMY_SP,
+    common::{collections::AHashSet, comments::Comments, Span, Spanned, SyntaxContext, DUMMY_SP},
     ecma::{
         ast::*,
-        utils::{private_ident, quote_ident, ExprFactory},
+        utils::{collect_decls, private_ident, quote_ident, ExprFactory},
         visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith},
     },
 };
-use utils::{decl_var_and_assign_stmt, global_module_api_call_expr, obj_lit, obj_member_expr};
+use tracing::debug;
+use utils::{
+    contains_jsx, decl_var_and_assign_stmt, getter_prop, has_leading_pragma, import_attributes_key,
+    module_export_name, module_ident_name, obj_lit, obj_member_expr, prop_name, used_idents,
+};
+
+/// Module idents and import attributes, keyed by module source and import attributes key.
+type ImportIdents = BTreeMap<(String, String), (Ident, Option<Box<ObjectLit>>)>;
 
-pub struct GlobalEsmModule {
+pub struct GlobalEsmModule<C: Comments> {
     module_name: String,
-    runtime_module: bool,
-    import_paths: Option<HashMap<String, String>>,
-    import_idents: BTreeMap<String, Ident>,
-    normalize_regex: Regex,
+    config: GlobalEsmConfig,
+    import_idents: ImportIdents,
+    comments: C,
+    adapter: Arc<dyn RuntimeAdapter>,
 }
 
-impl GlobalEsmModule {
-    fn default(
+impl<C: Comments> GlobalEsmModule<C> {
+    fn default(module_name: String, config: GlobalEsmConfig, comments: C) -> Self {
+        let adapter = Arc::new(GlobalModulesAdapter::new(&config));
+        GlobalEsmModule::new(module_name, config, comments, adapter)
+    }
+
+    fn new(
         module_name: String,
-        runtime_module: bool,
-        import_paths: Option<HashMap<String, String>>,
+        config: GlobalEsmConfig,
+        comments: C,
+        adapter: Arc<dyn RuntimeAdapter>,
     ) -> Self {
         GlobalEsmModule {
             module_name,
-            runtime_module,
-            import_paths,
+            config,
             import_idents: BTreeMap::new(),
-            normalize_regex: Regex::new(r"[^a-zA-Z0-9]").unwrap(),
+            comments,
+            adapter,
         }
     }
 
-    /// Find actual module path from `import_paths`
-    fn to_actual_path(&self, module_src: String) -> String {
-        if let Some(actual_path) = self
-            .import_paths
-            .as_ref()
-            .and_then(|import_paths| import_paths.get(&module_src))
-        {
-            return actual_path.clone();
-        }
-        module_src
+    /// Returns the runtime import call of the module.
+    ///
+    /// eg. `global.__modules.import(module_src)`
+    fn get_import_expr(&self, span: Span, module_src: &str, with: &Option<Box<ObjectLit>>) -> Expr {
+        self.adapter.import(
+            span,
+            &self.config.to_actual_path(module_src),
+            with.as_deref(),
+        )
     }
 
     /// Returns an statement that import module from global and assign it.
     ///
     /// eg. `const __mod = global.__modules.import(module_src)`
-    fn get_global_import_stmt(&self, ident: &Ident, module_src: &str) -> Stmt {
-        decl_var_and_assign_stmt(
-            ident,
-            global_module_api_call_expr(
-                MODULE_IMPORT_METHOD_NAME,
-                vec![self.to_actual_path(String::from(module_src)).as_arg()],
-            ),
-        )
+    fn get_global_import_stmt(
+        &self,
+        ident: &Ident,
+        module_src: &str,
+        with: &Option<Box<ObjectLit>>,
+    ) -> Stmt {
+        let span = ident.span.with_ctxt(SyntaxContext::empty());
+        decl_var_and_assign_stmt(span, ident, self.get_import_expr(span, module_src, with))
     }
 
     /// Returns a cached module ident.
-    fn get_module_ident(&mut self, module_src: &String) -> &Ident {
-        self.import_idents
-            .entry(module_src.clone())
-            .or_insert(private_ident!(self
-                .normalize_regex
-                .replace_all(format!("_{module_src}").as_str(), "_")
-                .to_string()))
+    ///
+    /// The ident takes the span of the first import declaration of the module.
+    /// Imports of the same module with different attributes have their own idents.
+    fn get_module_ident(
+        &mut self,
+        module_src: &str,
+        with: &Option<Box<ObjectLit>>,
+        span: Span,
+    ) -> &Ident {
+        let attributes_key = with
+            .as_deref()
+            .map(import_attributes_key)
+            .unwrap_or_default();
+        let ident_name = module_ident_name(module_src);
+        &self
+            .import_idents
+            .entry((module_src.to_string(), attributes_key))
+            .or_insert_with(|| (private_ident!(span, ident_name), with.clone()))
+            .0
+    }
+
+    /// Returns the module wrapped with the default interop method of the runtime.
+    ///
+    /// eg. `global.__modules.interopDefault(module)` (babel)
+    /// eg. `global.__modules.interopDefault(module, true)` (node)
+    fn interop_default_expr(&self, span: Span, module: Expr) -> Expr {
+        match self.config.interop {
+            Interop::None => module,
+            Interop::Babel => self.adapter.interop_default(span, module, false),
+            Interop::Node => self.adapter.interop_default(span, module, true),
+        }
     }
 
     /// Create unique module identifier and returns a statement that import default value from global.
     ///
     /// eg. `const ident = {module_ident}.default`
     /// eg. `import ident from "module_src"`
-    fn create_default_import_stmt(&mut self, module_src: &String, ident: &Ident) -> ModuleItem {
-        if self.runtime_module {
-            let module_ident = self.get_module_ident(&module_src);
+    fn create_default_import_stmt(
+        &mut self,
+        module_src: &str,
+        ident: &Ident,
+        with: &Option<Box<ObjectLit>>,
+        span: Span,
+    ) -> ModuleItem {
+        if self.config.runtime_module {
+            let module_ident = self.get_module_ident(module_src, with, span).clone();
+            let module = self.interop_default_expr(span, module_ident.into());
             decl_var_and_assign_stmt(
-                &ident,
-                obj_member_expr(module_ident.clone().into(), quote_ident!("default")),
+                span,
+                ident,
+                obj_member_expr(module, quote_ident!("default")),
             )
             .into()
         } else {
             ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                span: DUMMY_SP,
+                span,
                 specifiers: vec![ImportDefaultSpecifier {
                     span: DUMMY_SP,
                     local: ident.clone(),
                 }
                 .into()],
-                src: Str::from(module_src.clone()).into(),
+                src: Str::from(module_src).into(),
                 type_only: false,
-                with: None,
+                with: with.clone(),
             }))
         }
     }
@@ -111,14 +171,17 @@ impl GlobalEsmModule {
     /// eg. `import { ident } from "module_src"`
     fn create_named_import_stmt(
         &mut self,
-        module_src: &String,
+        module_src: &str,
         ident: &Ident,
         imported: &Option<Ident>,
+        with: &Option<Box<ObjectLit>>,
+        span: Span,
     ) -> ModuleItem {
-        if self.runtime_module {
-            let module_ident = self.get_module_ident(&module_src);
+        if self.config.runtime_module {
+            let module_ident = self.get_module_ident(module_src, with, span);
             decl_var_and_assign_stmt(
-                &ident,
+                span,
+                ident,
                 obj_member_expr(
                     module_ident.clone().into(),
                     quote_ident!(imported.clone().unwrap_or(ident.clone()).sym),
@@ -127,19 +190,17 @@ impl GlobalEsmModule {
             .into()
         } else {
             ModuleDecl::Import(ImportDecl {
-                span: DUMMY_SP,
+                span,
                 specifiers: vec![ImportNamedSpecifier {
                     span: DUMMY_SP,
                     local: ident.clone(),
-                    imported: imported.clone().and_then(|imported_ident| {
-                        Some(ModuleExportName::Ident(imported_ident.into()))
-                    }),
+                    imported: imported.clone().map(module_export_name),
                     is_type_only: false,
                 }
                 .into()],
-                src: Str::from(module_src.clone()).into(),
+                src: Str::from(module_src).into(),
                 type_only: false,
-                with: None,
+                with: with.clone(),
             })
             .into()
         }
@@ -149,22 +210,33 @@ impl GlobalEsmModule {
     ///
     /// eg. `const ident = global.__modules.importAll(module_src)`
     /// eg. `import * as ident from "module_src"`
-    fn create_namespace_import_stmt(&mut self, module_src: &String, ident: &Ident) -> ModuleItem {
-        if self.runtime_module {
-            decl_var_and_assign_stmt(
-                &ident,
-                global_module_api_call_expr(
-                    MODULE_IMPORT_WILDCARD_METHOD_NAME,
-                    vec![Str::from(self.to_actual_path(module_src.clone())).as_arg()],
+    fn create_namespace_import_stmt(
+        &mut self,
+        module_src: &str,
+        ident: &Ident,
+        with: &Option<Box<ObjectLit>>,
+        span: Span,
+    ) -> ModuleItem {
+        if self.config.runtime_module {
+            let module = match self.config.interop {
+                Interop::None => self.adapter.import_wildcard(
+                    span,
+                    &self.config.to_actual_path(module_src),
+                    with.as_deref(),
                 ),
-            )
-            .into()
+                Interop::Babel | Interop::Node => self.adapter.interop_wildcard(
+                    span,
+                    self.get_import_expr(span, module_src, with),
+                    self.config.interop == Interop::Node,
+                ),
+            };
+            decl_var_and_assign_stmt(span, ident, module).into()
         } else {
             ModuleDecl::Import(ImportDecl {
-                span: DUMMY_SP,
-                src: Str::from(module_src.clone()).into(),
+                span,
+                src: Str::from(module_src).into(),
                 type_only: false,
-                with: None,
+                with: with.clone(),
                 specifiers: vec![ImportStarAsSpecifier {
                     span: DUMMY_SP,
                     local: ident.clone(),
@@ -178,34 +250,19 @@ impl GlobalEsmModule {
     /// Returns a statement that initialize the global module.
     ///
     /// eg. `global.__modules.init(module_name)`
-    fn get_init_global_export_stmt(&mut self) -> Stmt {
-        Stmt::Expr(ExprStmt {
-            span: DUMMY_SP,
-            expr: obj_member_expr(
-                obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE)),
-                quote_ident!(MODULE_INIT_METHOD_NAME),
-            )
-            .as_call(DUMMY_SP, vec![Str::from(self.module_name.clone()).as_arg()])
-            .into(),
-        })
+    /// eg. `global.__modules.init(module_name, true)` (CommonJS module)
+    fn get_init_global_export_stmt(&mut self, span: Span, cjs: bool) -> Stmt {
+        self.adapter.init(span, &self.module_name, cjs)
     }
 
     /// Returns a statement that reset the global module.
     ///
     /// eg. `global.__modules.reset(module_name)`
     fn get_reset_global_export_stmt(&mut self) -> Stmt {
-        Stmt::Expr(ExprStmt {
-            span: DUMMY_SP,
-            expr: obj_member_expr(
-                obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE)),
-                quote_ident!(MODULE_RESET_METHOD_NAME),
-            )
-            .as_call(DUMMY_SP, vec![Str::from(self.module_name.clone()).as_arg()])
-            .into(),
-        })
+        self.adapter.reset(DUMMY_SP, &self.module_name)
     }
 
-    fn convert_esm_import(&mut self, imports: &Vec<ImportModule>) -> Vec<ModuleItem> {
+    fn convert_esm_import(&mut self, imports: &[ImportModule]) -> Vec<ModuleItem> {
         let mut stmts = Vec::with_capacity(imports.len());
 
         imports.iter().for_each(
@@ -214,25 +271,27 @@ impl GlobalEsmModule {
                  imported,
                  module_src,
                  module_type,
+                 with,
+                 span,
              }| match module_type {
                 ModuleType::Default | ModuleType::DefaultAsNamed => {
-                    stmts.push(self.create_default_import_stmt(module_src, ident).into());
+                    stmts.push(self.create_default_import_stmt(module_src, ident, with, *span));
                 }
-                ModuleType::Named => stmts.push(
-                    self.create_named_import_stmt(module_src, ident, imported)
-                        .into(),
-                ),
+                ModuleType::Named => stmts
+                    .push(self.create_named_import_stmt(module_src, ident, imported, with, *span)),
                 ModuleType::NamespaceOrAll => {
-                    stmts.push(self.create_namespace_import_stmt(module_src, ident).into())
+                    stmts.push(self.create_namespace_import_stmt(module_src, ident, with, *span))
                 }
             },
         );
 
-        if self.runtime_module {
+        if self.config.runtime_module {
             let mut import_ident_stmts = self
                 .import_idents
                 .iter()
-                .map(|(src, ident)| self.get_global_import_stmt(ident, src).into())
+                .map(|((src, _), (ident, with))| {
+                    self.get_global_import_stmt(ident, src, with).into()
+                })
                 .collect::<Vec<ModuleItem>>();
 
             import_ident_stmts.extend(stmts);
@@ -242,113 +301,522 @@ impl GlobalEsmModule {
         }
     }
 
-    fn convert_esm_export(&mut self, exports: &Vec<ExportModule>) -> Vec<ModuleItem> {
+    /// Returns export statements of the module.
+    ///
+    /// `initialized` is `true` when the module is already initialized by CommonJS exports.
+    fn convert_esm_export(
+        &mut self,
+        exports: &[ExportModule],
+        initialized: bool,
+    ) -> Vec<ModuleItem> {
         let mut stmts = Vec::with_capacity(exports.len());
         if exports.is_empty() {
-            stmts.push(self.get_reset_global_export_stmt().into());
+            if !initialized {
+                stmts.push(self.get_reset_global_export_stmt().into());
+            }
         } else {
             let mut export_props = Vec::new();
-            let mut export_all_props = Vec::new();
-            exports.into_iter().for_each(
+            let mut export_all_elems = Vec::new();
+            let mut export_span = None;
+            let mut export_all_span = None;
+            exports.iter().for_each(
                 |ExportModule {
                      ident,
                      as_ident,
                      module_type,
+                     reassigned,
+                     span,
                  }| {
                     match module_type {
                         ModuleType::Default | ModuleType::DefaultAsNamed => {
+                            export_span.get_or_insert(*span);
                             export_props.push(
-                                Prop::KeyValue(KeyValueProp {
-                                    key: quote_ident!("default").into(),
-                                    value: ident.clone().into(),
-                                })
+                                if *reassigned {
+                                    getter_prop(
+                                        *span,
+                                        quote_ident!(*span, "default"),
+                                        ident.clone(),
+                                    )
+                                } else {
+                                    Prop::KeyValue(KeyValueProp {
+                                        key: quote_ident!(*span, "default").into(),
+                                        value: ident.clone().into(),
+                                    })
+                                }
                                 .into(),
                             );
                         }
                         ModuleType::Named => {
+                            export_span.get_or_insert(*span);
+                            let renamed_ident = as_ident.as_ref().filter(|&id| id.sym != ident.sym);
                             export_props.push(
-                                if let Some(renamed_ident) =
-                                    as_ident.as_ref().filter(|&id| id.sym != ident.sym)
-                                {
-                                    Prop::KeyValue(KeyValueProp {
-                                        key: quote_ident!(renamed_ident.sym.as_str()).into(),
+                                match (renamed_ident, reassigned) {
+                                    (Some(renamed_ident), true) => getter_prop(
+                                        *span,
+                                        quote_ident!(*span, renamed_ident.sym.as_str()),
+                                        ident.clone(),
+                                    ),
+                                    (None, true) => getter_prop(
+                                        *span,
+                                        quote_ident!(*span, ident.sym.as_str()),
+                                        ident.clone(),
+                                    ),
+                                    (Some(renamed_ident), false) => Prop::KeyValue(KeyValueProp {
+                                        key: prop_name(quote_ident!(
+                                            *span,
+                                            renamed_ident.sym.as_str()
+                                        )),
                                         value: ident.clone().into(),
-                                    })
-                                    .into()
-                                } else {
-                                    Prop::Shorthand(ident.clone()).into()
-                                },
+                                    }),
+                                    (None, false) => Prop::Shorthand(ident.clone()),
+                                }
+                                .into(),
                             );
                         }
-                        ModuleType::NamespaceOrAll => export_all_props.push(
-                            SpreadElement {
-                                dot3_token: DUMMY_SP,
-                                expr: ident.clone().into(),
-                            }
-                            .into(),
-                        ),
+                        ModuleType::NamespaceOrAll => {
+                            export_all_span.get_or_insert(*span);
+                            export_all_elems.push(Some(ident.clone().as_arg()))
+                        }
                     }
                 },
             );
-            stmts.push(self.get_init_global_export_stmt().into());
+            if !initialized {
+                stmts.push(
+                    self.get_init_global_export_stmt(exports[0].span, false)
+                        .into(),
+                );
+            }
 
-            if !export_props.is_empty() {
+            if let Some(span) = export_span {
                 stmts.push(
-                    global_module_api_call_expr(
-                        MODULE_EXPORT_METHOD_NAME,
-                        vec![
-                            self.module_name.as_str().as_arg(),
-                            obj_lit(Some(export_props)).as_arg(),
-                        ],
-                    )
-                    .into_stmt()
+                    Stmt::Expr(ExprStmt {
+                        span,
+                        expr: Box::new(self.adapter.export(
+                            span,
+                            &self.module_name,
+                            obj_lit(span, Some(export_props)),
+                        )),
+                    })
                     .into(),
                 );
             }
 
-            if !export_all_props.is_empty() {
+            if let Some(span) = export_all_span {
                 stmts.push(
-                    global_module_api_call_expr(
-                        MODULE_EXPORT_ALL_METHOD_NAME,
-                        vec![
-                            self.module_name.as_str().as_arg(),
-                            obj_lit(Some(export_all_props)).as_arg(),
-                        ],
-                    )
+                    self.adapter
+                        .export_all(
+                            span,
+                            &self.module_name,
+                            ArrayLit {
+                                span,
+                                elems: export_all_elems,
+                            },
+                            self.config.warn_ambiguous_exports,
+                        )
+                        .into(),
+                );
+            }
+        }
+        stmts
+    }
+
+    /// Wrap the module body with an async factory that runs after its dependencies are ready.
+    ///
+    /// eg. `global.__modules.asyncModule(module_name, [deps], async () => { ... })`
+    fn wrap_async_module<'a>(
+        &self,
+        module: &mut Module,
+        module_sources: impl IntoIterator<Item = &'a str>,
+        directives: Vec<Stmt>,
+    ) {
+        let mut deps: Vec<String> = Vec::new();
+        module_sources.into_iter().for_each(|module_src| {
+            let dep = self.config.to_actual_path(module_src);
+            if !deps.contains(&dep) {
+                deps.push(dep);
+            }
+        });
+
+        let stmts = take_module_stmts(module, directives);
+        module.body.push(
+            self.adapter
+                .async_module(
+                    DUMMY_SP,
+                    &self.module_name,
+                    ArrayLit {
+                        span: DUMMY_SP,
+                        elems: deps.into_iter().map(|dep| Some(dep.as_arg())).collect(),
+                    },
+                    ArrowExpr {
+                        span: DUMMY_SP,
+                        params: Vec::new(),
+                        body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                            span: DUMMY_SP,
+                            stmts,
+                        })),
+                        is_async: true,
+                        is_generator: false,
+                        type_params: None,
+                        return_type: None,
+                    }
+                    .into(),
+                )
+                .into(),
+        );
+    }
+
+    /// Wrap the module body with an IIFE or a block to isolate its top-level declarations.
+    ///
+    /// eg. `(() => { ... })();`
+    /// eg. `{ ... }`
+    fn wrap_module(&self, module: &mut Module, directives: Vec<Stmt>, has_top_level_await: bool) {
+        match self.config.wrap {
+            // `await` is not allowed in the IIFE.
+            Wrap::Iife if !has_top_level_await => {
+                let stmts = take_module_stmts(module, directives);
+                module.body.push(
+                    ArrowExpr {
+                        span: DUMMY_SP,
+                        params: Vec::new(),
+                        body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                            span: DUMMY_SP,
+                            stmts,
+                        })),
+                        is_async: false,
+                        is_generator: false,
+                        type_params: None,
+                        return_type: None,
+                    }
+                    .as_iife()
                     .into_stmt()
                     .into(),
                 );
             }
+            Wrap::Iife | Wrap::Block => {
+                // Directives are only effective at the top of the program.
+                let stmts = take_module_stmts(module, Vec::new());
+                module.body.push(
+                    Stmt::Block(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts,
+                    })
+                    .into(),
+                );
+                module
+                    .body
+                    .splice(..0, directives.into_iter().map(ModuleItem::from));
+            }
+            Wrap::None => {
+                module
+                    .body
+                    .splice(..0, directives.into_iter().map(ModuleItem::from));
+            }
         }
-        stmts
+    }
+
+    /// Remove imports whose bindings are never referenced in the module and exports.
+    ///
+    /// Returns the used imports and the removed imports.
+    fn remove_unused_imports(
+        &self,
+        module: &Module,
+        imports: Vec<ImportModule>,
+        exports: &[ExportModule],
+    ) -> (Vec<ImportModule>, Vec<ImportModule>) {
+        let mut used = used_idents(module);
+        used.extend(exports.iter().map(|export| export.ident.to_id()));
+        // JSX of the classic runtime is converted to the pragma calls after this transform.
+        if contains_jsx(module) {
+            let pragma_root = self.config.jsx_pragma_root();
+            used.extend(
+                imports
+                    .iter()
+                    .filter(|import| &*import.ident.sym == pragma_root)
+                    .map(|import| import.ident.to_id()),
+            );
+        }
+
+        imports.into_iter().partition(|import| {
+            let is_used = used.contains(&import.ident.to_id());
+            if !is_used {
+                debug!("unused import: {:#?}", import.ident);
+            }
+            is_used
+        })
+    }
+
+    /// Returns the imports without bindings of side-effectful modules in source order
+    /// (one for each module that has no used imports).
+    ///
+    /// eg. `import './polyfill'` and the removed imports of `./polyfill`
+    fn side_effect_imports(
+        &self,
+        mut bare_imports: Vec<BareImportInfo>,
+        imports: &[ImportModule],
+    ) -> Vec<BareImportInfo> {
+        let mut side_effect_imports: Vec<BareImportInfo> = Vec::new();
+        bare_imports.sort_by_key(|bare_import| bare_import.span.lo);
+        bare_imports.into_iter().for_each(|bare_import| {
+            let with_key = bare_import.with.as_deref().map(import_attributes_key);
+            let is_imported = |module_src: &str, with: &Option<Box<ObjectLit>>| {
+                module_src == bare_import.module_src
+                    && with.as_deref().map(import_attributes_key) == with_key
+            };
+            if self.config.has_side_effects(&bare_import.module_src)
+                && !imports
+                    .iter()
+                    .any(|import| is_imported(&import.module_src, &import.with))
+                && !side_effect_imports
+                    .iter()
+                    .any(|other| is_imported(&other.module_src, &other.with))
+            {
+                side_effect_imports.push(bare_import);
+            }
+        });
+        side_effect_imports
+    }
+
+    /// Check the file starts with `// @global-esm-ignore` comment.
+    fn is_ignored(&self, span: Span, first_item_span: Option<Span>) -> bool {
+        has_leading_pragma(&self.comments, span.lo, IGNORE_FILE_PRAGMA)
+            || first_item_span.is_some_and(|first_item_span| {
+                has_leading_pragma(&self.comments, first_item_span.lo, IGNORE_FILE_PRAGMA)
+            })
+    }
+
+    /// Convert CommonJS `require()` calls and exports of the program.
+    ///
+    /// Returns `true` when the program has CommonJS exports.
+    fn convert_cjs<N>(&self, program: &mut N, bindings: AHashSet<Id>) -> bool
+    where
+        N: for<'a> VisitMutWith<CjsModuleCollector<'a>>,
+    {
+        if !(self.config.runtime_module && self.config.cjs && self.config.target == Target::Global)
+        {
+            return false;
+        }
+
+        let mut cjs_collector =
+            CjsModuleCollector::new(&self.module_name, &self.config, &*self.adapter, bindings);
+        program.visit_mut_with(&mut cjs_collector);
+        cjs_collector.has_exports
+    }
+
+    /// Convert dynamic imports of the program with the runtime adapter.
+    fn convert_dynamic_import<N>(&self, program: &mut N)
+    where
+        N: for<'a> VisitMutWith<DynamicImportConverter<'a>>,
+    {
+        if !(self.config.runtime_module && self.config.target == Target::Global) {
+            return;
+        }
+
+        program.visit_mut_with(&mut DynamicImportConverter {
+            adapter: &*self.adapter,
+            config: &self.config,
+        });
+    }
+
+    /// Convert `import.meta.hot` of the module to its hot context (`hmr`).
+    ///
+    /// Returns the declaration of the hot context if the module uses it.
+    ///
+    /// eg. `const __hot = global.__modules.hot(module_name)`
+    fn convert_import_meta_hot(&self, module: &mut Module) -> Option<Stmt> {
+        if !(self.config.hmr && self.config.runtime_module && self.config.target == Target::Global)
+        {
+            return None;
+        }
+
+        let mut converter = ImportMetaHotConverter { hot_ident: None };
+        module.visit_mut_with(&mut converter);
+        converter.hot_ident.map(|hot_ident| {
+            decl_var_and_assign_stmt(
+                DUMMY_SP,
+                &hot_ident,
+                self.adapter.hot(DUMMY_SP, &self.module_name),
+            )
+        })
     }
 }
 
-impl VisitMut for GlobalEsmModule {
+impl<C: Comments> VisitMut for GlobalEsmModule<C> {
     noop_visit_mut_type!();
 
     fn visit_mut_module(&mut self, module: &mut Module) {
-        let mut esm_collector = EsModuleCollector::new(self.runtime_module);
-        module.visit_mut_with(&mut esm_collector);
+        if self.is_ignored(module.span, module.body.first().map(|item| item.span())) {
+            return;
+        }
 
-        module
-            .body
-            .splice(..0, self.convert_esm_import(&esm_collector.imports));
+        let has_cjs_exports = self.convert_cjs(module, collect_decls(&*module));
+        self.convert_dynamic_import(module);
+        let hot_stmt = self.convert_import_meta_hot(module);
+
+        let (imports, bare_imports, exports, has_top_level_await) = {
+            let mut esm_collector =
+                EsModuleCollector::new(self.config.runtime_module, &self.comments);
+            module.visit_mut_with(&mut esm_collector);
+            (
+                esm_collector.imports,
+                esm_collector.bare_imports,
+                esm_collector.exports,
+                esm_collector.has_top_level_await,
+            )
+        };
+
+        if self.config.runtime_module && self.config.target == Target::SystemJs {
+            self.convert_system_module(module, &imports, &exports, has_top_level_await);
+            return;
+        }
+
+        let (imports, unused_imports) =
+            if self.config.runtime_module && self.config.remove_unused_imports {
+                self.remove_unused_imports(module, imports, &exports)
+            } else {
+                (imports, Vec::new())
+            };
+        let side_effect_imports = self.side_effect_imports(
+            bare_imports
+                .iter()
+                .cloned()
+                .chain(unused_imports.into_iter().map(BareImportInfo::from))
+                .collect(),
+            &imports,
+        );
+
+        let directives = take_directives(module);
+        if let Some(hot_stmt) = hot_stmt {
+            module.body.insert(0, hot_stmt.into());
+        }
+        module.body.splice(..0, self.convert_esm_import(&imports));
+        module.body.splice(
+            ..0,
+            side_effect_imports.iter().map(|import| {
+                Stmt::Expr(ExprStmt {
+                    span: import.span,
+                    expr: Box::new(self.get_import_expr(
+                        import.span,
+                        &import.module_src,
+                        &import.with,
+                    )),
+                })
+                .into()
+            }),
+        );
+
+        if has_cjs_exports {
+            // Modules that have ES module exports are not treated as CommonJS modules.
+            let init_stmt = self.get_init_global_export_stmt(DUMMY_SP, exports.is_empty());
+            module.body.insert(0, init_stmt.into());
+        }
 
         module
             .body
-            .extend(self.convert_esm_export(&esm_collector.exports));
+            .extend(self.convert_esm_export(&exports, has_cjs_exports));
+
+        if self.config.runtime_module
+            && self.config.async_mode
+            && (has_top_level_await || !imports.is_empty() || !bare_imports.is_empty())
+        {
+            // Bare imports of any module are dependencies, even if they are not imported at runtime.
+            self.wrap_async_module(
+                module,
+                bare_imports
+                    .iter()
+                    .chain(&side_effect_imports)
+                    .map(|import| import.module_src.as_str())
+                    .chain(imports.iter().map(|import| import.module_src.as_str())),
+                directives,
+            );
+        } else if self.config.runtime_module {
+            self.wrap_module(module, directives, has_top_level_await);
+        } else {
+            module
+                .body
+                .splice(..0, directives.into_iter().map(ModuleItem::from));
+        }
     }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        if self.is_ignored(script.span, script.body.first().map(|stmt| stmt.span())) {
+            return;
+        }
+
+        let has_cjs_exports = self.convert_cjs(script, collect_decls(&*script));
+        self.convert_dynamic_import(script);
+
+        if has_cjs_exports {
+            script
+                .body
+                .insert(0, self.get_init_global_export_stmt(DUMMY_SP, true));
+        }
+    }
+}
+
+/// Check the statement is a directive (eg. `"use strict"`).
+fn is_directive(stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Expr(ExprStmt { expr, .. }) if matches!(&**expr, Expr::Lit(Lit::Str(_))))
+}
+
+/// Take the directive prologue (eg. `"use strict"`) of the module.
+fn take_directives(module: &mut Module) -> Vec<Stmt> {
+    let len = module
+        .body
+        .iter()
+        .take_while(|item| item.as_stmt().is_some_and(is_directive))
+        .count();
+
+    module
+        .body
+        .drain(..len)
+        .filter_map(|item| item.stmt())
+        .collect()
+}
+
+/// Take the statements of the module with the directives at the top.
+///
+/// Module declarations (eg. kept by pragma) remain at the top level.
+fn take_module_stmts(module: &mut Module, directives: Vec<Stmt>) -> Vec<Stmt> {
+    let (module_decls, items): (Vec<_>, Vec<_>) = std::mem::take(&mut module.body)
+        .into_iter()
+        .partition(|item| item.is_module_decl());
+
+    module.body = module_decls;
+    directives
+        .into_iter()
+        .chain(items.into_iter().filter_map(|item| item.stmt()))
+        .collect()
 }
 
-pub fn global_esm(
+pub fn global_esm<C: Comments>(
     module_name: String,
-    runtime_module: bool,
-    import_paths: Option<HashMap<String, String>>,
+    config: GlobalEsmConfig,
+    comments: C,
+) -> impl VisitMut + Fold {
+    as_folder(GlobalEsmModule::default(module_name, config, comments))
+}
+
+/// Same as `global_esm`, but generates the runtime calls with the given adapter.
+///
+/// Use this to target module registries other than `global.__modules`.
+pub fn global_esm_with_adapter<C: Comments>(
+    module_name: String,
+    config: GlobalEsmConfig,
+    comments: C,
+    adapter: Arc<dyn RuntimeAdapter>,
+) -> impl VisitMut + Fold {
+    as_folder(GlobalEsmModule::new(module_name, config, comments, adapter))
+}
+
+/// Converts import statements injected after the `global_esm` transform to the global module API.
+///
+/// Run this as a late pass (eg. after helpers injection and JSX transform) in runtime mode
+/// so that `@swc/helpers` and `react/jsx-runtime` imports are resolved from the global module.
+/// Imports with `/* @global-esm-keep */` comment are kept, and the converted imports are placed
+/// after the directives of the module body (in the wrapper of `wrap` or `async_mode` if any).
+pub fn global_esm_late_imports<C: Comments>(
+    config: GlobalEsmConfig,
+    comments: C,
 ) -> impl VisitMut + Fold {
-    as_folder(GlobalEsmModule::default(
-        module_name,
-        runtime_module,
-        import_paths,
-    ))
+    as_folder(LateImports::new(config, comments))
 }
@@ -0,0 +1,247 @@
+use crate::{adapter::RuntimeAdapter, config::GlobalEsmConfig, utils::obj_lit};
+use swc_core::{
+    common::{collections::AHashSet, util::take::Take, Span, Spanned},
+    ecma::{
+        ast::*,
+        atoms::JsWord,
+        utils::{quote_ident, quote_str},
+        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+    },
+};
+use tracing::debug;
+
+/// Converts CommonJS `require()` calls and `module.exports` assignments to the global module API.
+pub struct CjsModuleCollector<'a> {
+    module_name: &'a str,
+    config: &'a GlobalEsmConfig,
+    adapter: &'a dyn RuntimeAdapter,
+    bindings: AHashSet<Id>,
+    pub has_exports: bool,
+}
+
+impl<'a> CjsModuleCollector<'a> {
+    /// `bindings` are the declared bindings of the program (eg. from `collect_decls`).
+    pub fn new(
+        module_name: &'a str,
+        config: &'a GlobalEsmConfig,
+        adapter: &'a dyn RuntimeAdapter,
+        bindings: AHashSet<Id>,
+    ) -> Self {
+        CjsModuleCollector {
+            module_name,
+            config,
+            adapter,
+            bindings,
+            has_exports: false,
+        }
+    }
+
+    /// Check the ident is a global binding (not declared in the module) named `name`.
+    fn is_free_ident(&self, ident: &Ident, name: &str) -> bool {
+        ident.sym == *name && !self.bindings.contains(&ident.to_id())
+    }
+
+    /// Check the expression is `module.exports`.
+    fn is_module_exports(&self, expr: &Expr) -> bool {
+        matches!(
+            expr,
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) if prop.sym == *"exports"
+                && matches!(&**obj, Expr::Ident(module_ident) if self.is_free_ident(module_ident, "module"))
+        )
+    }
+
+    /// Check the expression is `module.exports` or `exports`.
+    fn is_exports_object(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => self.is_free_ident(ident, "exports"),
+            _ => self.is_module_exports(expr),
+        }
+    }
+
+    /// Returns the export name of `exports.name` or `module.exports.name`.
+    fn get_export_name(&self, member_expr: &MemberExpr) -> Option<JsWord> {
+        if !self.is_exports_object(&member_expr.obj) {
+            return None;
+        }
+
+        match &member_expr.prop {
+            MemberProp::Ident(ident) => Some(ident.sym.clone()),
+            MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+                Expr::Lit(Lit::Str(str)) => Some(str.value.clone()),
+                _ => None,
+            },
+            MemberProp::PrivateName(_) => None,
+        }
+    }
+
+    /// Returns `global.__modules.export(module_name, { name: value })` expression.
+    fn export_expr(&mut self, span: Span, export_name: JsWord, value: Box<Expr>) -> Expr {
+        self.has_exports = true;
+        let key = if Ident::verify_symbol(&export_name).is_ok() {
+            PropName::Ident(quote_ident!(export_name))
+        } else {
+            PropName::Str(quote_str!(export_name))
+        };
+        self.adapter.export(
+            span,
+            self.module_name,
+            obj_lit(
+                span,
+                Some(vec![Prop::KeyValue(KeyValueProp { key, value }).into()]),
+            ),
+        )
+    }
+
+    /// Returns the current value of the export (`global.__modules.import(module_name).name`).
+    fn export_value_expr(&mut self, member_expr: &MemberExpr) -> Box<Expr> {
+        let mut value = Box::new(Expr::Member(member_expr.clone()));
+        value.visit_mut_with(self);
+        value
+    }
+
+    /// Returns the module source of `require("module_src")`.
+    fn get_require_src(&self, call_expr: &CallExpr) -> Option<JsWord> {
+        match (&call_expr.callee, &call_expr.args[..]) {
+            (Callee::Expr(callee), [ExprOrSpread { spread: None, expr }]) if matches!(&**callee, Expr::Ident(ident) if self.is_free_ident(ident, "require")) =>
+            {
+                match &**expr {
+                    Expr::Lit(Lit::Str(str)) => Some(str.value.clone()),
+                    // `require(\`src\`)`
+                    Expr::Tpl(Tpl { exprs, quasis, .. }) if exprs.is_empty() => {
+                        quasis.first().and_then(|quasi| quasi.cooked.clone())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns the assignment target as an expression.
+fn get_assign_target(left: &PatOrExpr) -> Option<&Expr> {
+    match left {
+        PatOrExpr::Expr(expr) => Some(expr),
+        PatOrExpr::Pat(pat) => match &**pat {
+            Pat::Expr(expr) => Some(expr),
+            _ => None,
+        },
+    }
+}
+
+impl VisitMut for CjsModuleCollector<'_> {
+    noop_visit_mut_type!();
+
+    /// Convert CommonJS expressions.
+    ///
+    /// **Examples**
+    ///
+    /// - `require("src")` to `global.__modules.import("src")`
+    /// - `module.exports = expr` to `global.__modules.exportModule(module_name, expr)`
+    /// - `exports.name = expr` to `global.__modules.export(module_name, { name: expr })`
+    /// - `exports.name += expr` and `exports.name++` to
+    ///   `global.__modules.export(module_name, { name: <updated value> })`
+    /// - `module.exports` (read) to `global.__modules.import(module_name)`
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Assign(AssignExpr {
+                op: op!("="),
+                left,
+                right,
+                span,
+            }) => match get_assign_target(left) {
+                Some(target) if self.is_module_exports(target) => {
+                    debug!("cjs module.exports assignment");
+                    right.visit_mut_with(self);
+                    self.has_exports = true;
+                    *expr = self
+                        .adapter
+                        .export_module(*span, self.module_name, *right.take());
+                    return;
+                }
+                Some(Expr::Member(member_expr)) => {
+                    if let Some(export_name) = self.get_export_name(member_expr) {
+                        debug!("cjs named export: {:#?}", export_name);
+                        right.visit_mut_with(self);
+                        *expr = self.export_expr(*span, export_name, right.take());
+                        return;
+                    }
+                }
+                _ => {}
+            },
+            // `exports.name += expr` to `global.__modules.export(module_name, { name: ... + expr })`
+            Expr::Assign(AssignExpr {
+                op,
+                left,
+                right,
+                span,
+            }) => {
+                if let (Some(Expr::Member(member_expr)), Some(bin_op)) =
+                    (get_assign_target(left), op.to_update())
+                {
+                    if let Some(export_name) = self.get_export_name(member_expr) {
+                        debug!("cjs named export update: {:#?}", export_name);
+                        let current = self.export_value_expr(member_expr);
+                        right.visit_mut_with(self);
+                        let value = Box::new(Expr::Bin(BinExpr {
+                            span: *span,
+                            op: bin_op,
+                            left: current,
+                            right: right.take(),
+                        }));
+                        *expr = self.export_expr(*span, export_name, value);
+                        return;
+                    }
+                }
+            }
+            // `exports.name++` to `global.__modules.export(module_name, { name: +... + 1 })`
+            Expr::Update(UpdateExpr { op, arg, span, .. }) => {
+                if let Expr::Member(member_expr) = &**arg {
+                    if let Some(export_name) = self.get_export_name(member_expr) {
+                        debug!("cjs named export update: {:#?}", export_name);
+                        let current = self.export_value_expr(member_expr);
+                        let value = Box::new(Expr::Bin(BinExpr {
+                            span: *span,
+                            op: if *op == op!("++") {
+                                op!(bin, "+")
+                            } else {
+                                op!(bin, "-")
+                            },
+                            left: Box::new(Expr::Unary(UnaryExpr {
+                                span: *span,
+                                op: op!(unary, "+"),
+                                arg: current,
+                            })),
+                            right: 1.0.into(),
+                        }));
+                        *expr = self.export_expr(*span, export_name, value);
+                        return;
+                    }
+                }
+            }
+            Expr::Call(call_expr) => {
+                if let Some(module_src) = self.get_require_src(call_expr) {
+                    debug!("cjs require: {:#?}", module_src);
+                    *expr = self.adapter.import(
+                        call_expr.span,
+                        &self.config.to_actual_path(&module_src),
+                        None,
+                    );
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        if self.is_exports_object(expr) {
+            *expr = self.adapter.import(expr.span(), self.module_name, None);
+            return;
+        }
+
+        expr.visit_mut_children_with(self);
+    }
+}
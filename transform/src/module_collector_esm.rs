@@ -1,57 +1,68 @@
-use crate::utils::is_invalid_module_decl;
+use crate::{
+    analysis::{analyze_module, BareImportInfo, ImportInfo, ModuleInfo, ModuleType},
+    constants::KEEP_PRAGMA,
+    utils::{has_leading_pragma, is_invalid_module_decl},
+};
 use swc_core::{
-    common::util::take::Take,
+    common::{comments::Comments, util::take::Take, Span, Spanned, DUMMY_SP},
     ecma::{
         ast::*,
-        utils::{private_ident, ExprFactory},
+        utils::{find_pat_ids, private_ident, quote_ident, ExprFactory},
         visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
     },
 };
 use tracing::debug;
 
-#[derive(Debug)]
-pub enum ModuleType {
-    Default,
-    Named,
-    // `export { default as ... } from ...`
-    DefaultAsNamed,
-    // import: namespace, export: all
-    NamespaceOrAll,
-}
-
 #[derive(Debug)]
 pub struct ImportModule {
     pub ident: Ident,
     pub imported: Option<Ident>,
     pub module_src: String,
     pub module_type: ModuleType,
+    // Import attributes of the original declaration (eg. `with { type: "json" }`).
+    pub with: Option<Box<ObjectLit>>,
+    // Span of the original import (or re-export) declaration.
+    pub span: Span,
 }
 
 impl ImportModule {
-    fn default(ident: Ident, imported: Option<Ident>, module_src: String) -> Self {
+    fn namespace(
+        ident: Ident,
+        imported: Option<Ident>,
+        module_src: String,
+        with: Option<Box<ObjectLit>>,
+        span: Span,
+    ) -> Self {
         ImportModule {
             ident,
             imported,
             module_src,
-            module_type: ModuleType::Default,
+            module_type: ModuleType::NamespaceOrAll,
+            with,
+            span,
         }
     }
+}
 
-    fn named(ident: Ident, imported: Option<Ident>, module_src: String) -> Self {
-        ImportModule {
-            ident,
-            imported,
-            module_src,
-            module_type: ModuleType::Named,
+impl From<ImportModule> for BareImportInfo {
+    fn from(import: ImportModule) -> Self {
+        BareImportInfo {
+            module_src: import.module_src,
+            with: import.with,
+            span: import.span,
         }
     }
+}
 
-    fn namespace(ident: Ident, imported: Option<Ident>, module_src: String) -> Self {
+impl From<ImportInfo> for ImportModule {
+    fn from(import_info: ImportInfo) -> Self {
         ImportModule {
-            ident,
-            imported,
-            module_src,
-            module_type: ModuleType::NamespaceOrAll,
+            ident: import_info.ident,
+            imported: import_info.imported,
+            module_src: import_info.module_src,
+            module_type: import_info.module_type,
+            with: import_info.with,
+            span: import_info.span,
         }
     }
 }
@@ -63,50 +74,176 @@ pub struct ExportModule {
     // `a_1` in `export { a as a_1 };`
     pub as_ident: Option<Ident>,
     pub module_type: ModuleType,
+    // `true` if `a` is reassigned in the module.
+    pub reassigned: bool,
+    // Span of the original export declaration.
+    pub span: Span,
 }
 
 impl ExportModule {
-    fn default(ident: Ident) -> Self {
+    fn default(ident: Ident, span: Span) -> Self {
         ExportModule {
             ident,
             as_ident: None,
             module_type: ModuleType::Default,
+            reassigned: false,
+            span,
         }
     }
 
-    fn named(ident: Ident, as_ident: Option<Ident>) -> Self {
+    fn named(ident: Ident, as_ident: Option<Ident>, span: Span) -> Self {
         ExportModule {
             ident,
             as_ident,
             module_type: ModuleType::Named,
+            reassigned: false,
+            span,
         }
     }
 
-    fn all(ident: Ident, as_ident: Option<Ident>) -> Self {
+    fn all(ident: Ident, as_ident: Option<Ident>, span: Span) -> Self {
         ExportModule {
             ident,
             as_ident,
             module_type: ModuleType::NamespaceOrAll,
+            reassigned: false,
+            span,
         }
     }
 }
 
-pub struct EsModuleCollector {
+pub struct EsModuleCollector<C: Comments> {
     runtime_module: bool,
+    comments: C,
+    default_export_ident: Option<Ident>,
     pub imports: Vec<ImportModule>,
+    pub bare_imports: Vec<BareImportInfo>,
     pub exports: Vec<ExportModule>,
+    pub has_top_level_await: bool,
 }
 
-impl EsModuleCollector {
-    pub fn new(runtime_module: bool) -> Self {
+impl<C: Comments> EsModuleCollector<C> {
+    pub fn new(runtime_module: bool, comments: C) -> Self {
         EsModuleCollector {
             runtime_module,
+            comments,
+            default_export_ident: None,
             imports: Vec::new(),
+            bare_imports: Vec::new(),
             exports: Vec::new(),
+            has_top_level_await: false,
         }
     }
 
-    /// Collect `ExportModule` from default export expressions.
+    /// Collect `ImportModule` and `ExportModule` from the analyzed module.
+    ///
+    /// Anonymous default exports are named by the conversion, so it must be done before this.
+    ///
+    /// **Examples**
+    ///
+    /// - `import foo from 'src_1'`
+    /// - `import { bar, baz as baz2 } from 'src_2'`
+    /// - `export { a as a_1 }`
+    /// - `export * as ns from 'src_3'`
+    /// - `export { default as b } from 'src_4'`
+    /// - `export * from 'src_5'`
+    fn collect(&mut self, module_info: ModuleInfo) {
+        debug!("module info {:#?}", module_info);
+
+        self.has_top_level_await = module_info.has_top_level_await;
+
+        // Collect imports when `runtime_module` is `true`.
+        // If non-runtime, import statements are kept as it is.
+        if self.runtime_module {
+            self.imports
+                .extend(module_info.imports.into_iter().map(ImportModule::from));
+            self.bare_imports = module_info.bare_imports;
+        }
+
+        module_info.exports.into_iter().for_each(|export_info| {
+            let Some(ident) = export_info
+                .ident
+                .or_else(|| self.default_export_ident.clone())
+            else {
+                return;
+            };
+
+            let span = export_info.span;
+            let mut export_module = match export_info.module_type {
+                ModuleType::Default => ExportModule::default(ident, span),
+                _ => ExportModule::named(ident, Some(quote_ident!(span, export_info.name)), span),
+            };
+            export_module.reassigned = export_info.reassigned;
+            self.exports.push(export_module);
+        });
+
+        module_info
+            .re_exports
+            .into_iter()
+            .for_each(|re_export_info| {
+                let span = re_export_info.span;
+                let ident = private_ident!(span, "__re_export");
+                self.imports.push(match re_export_info.module_type {
+                    ModuleType::NamespaceOrAll => ImportModule::namespace(
+                        ident.clone(),
+                        None,
+                        re_export_info.module_src,
+                        re_export_info.with,
+                        span,
+                    ),
+                    module_type => ImportModule {
+                        ident: ident.clone(),
+                        imported: re_export_info.imported,
+                        module_src: re_export_info.module_src,
+                        module_type,
+                        with: re_export_info.with,
+                        span,
+                    },
+                });
+                self.exports.push(ExportModule::named(
+                    ident,
+                    Some(quote_ident!(span, re_export_info.name)),
+                    span,
+                ));
+            });
+
+        module_info
+            .star_exports
+            .into_iter()
+            .for_each(|star_export_info| {
+                let span = star_export_info.span;
+                let ident = private_ident!(span, "__re_export_all");
+                self.imports.push(ImportModule::namespace(
+                    ident.clone(),
+                    None,
+                    star_export_info.module_src,
+                    star_export_info.with,
+                    span,
+                ));
+                self.exports.push(ExportModule::all(ident, None, span));
+            });
+    }
+
+    /// Check the import or export declaration has `/* @global-esm-keep */` comment.
+    ///
+    /// Kept declarations are not converted and remain as ES module syntax.
+    fn is_kept(&self, span: Span) -> bool {
+        has_leading_pragma(&self.comments, span.lo, KEEP_PRAGMA)
+    }
+
+    /// Move leading and trailing comments of the original export declaration to its replacement.
+    ///
+    /// eg. JSDoc, `/* @__PURE__ */` or `// eslint-disable` comments above `export function ...`
+    fn move_comments(&self, from: Span, to: Span) {
+        if from.lo != to.lo {
+            self.comments.move_leading(from.lo, to.lo);
+        }
+        if from.hi != to.hi {
+            self.comments.move_trailing(from.hi, to.hi);
+        }
+    }
+
+    /// Convert default export expressions and name the default export.
     ///
     /// - `export default expr`
     ///
@@ -123,19 +260,32 @@ impl EsModuleCollector {
     /// ```
     fn collect_and_convert_export_default_expr(
         &mut self,
-        export_default_expr: &mut ExportDefaultExpr,
-    ) -> Stmt {
+        export_default_expr: ExportDefaultExpr,
+    ) -> Vec<ModuleItem> {
         debug!("export default expr {:#?}", export_default_expr);
-        let ident = private_ident!("__export_default");
-        self.exports.push(ExportModule::default(ident.clone()));
-        export_default_expr
-            .expr
-            .clone()
-            .into_var_decl(VarDeclKind::Const, ident.clone().into())
-            .into()
+        let ExportDefaultExpr { span, expr } = export_default_expr;
+        let ident = private_ident!(expr.span(), "__export_default");
+        self.default_export_ident = Some(ident.clone());
+        let mut items = vec![Stmt::Decl(Decl::Var(Box::new(VarDecl {
+            span,
+            ..expr.into_var_decl(VarDeclKind::Const, ident.clone().into())
+        })))
+        .into()];
+
+        // Keep the ES module default export of the host bundler.
+        if !self.runtime_module {
+            items.push(
+                ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                    span: DUMMY_SP,
+                    expr: Box::new(ident.into()),
+                })
+                .into(),
+            );
+        }
+        items
     }
 
-    /// Collect `ExportModule` default export with declare statements.
+    /// Convert default export with declare statements and name the default export if anonymous.
     ///
     /// - `export default function ...`
     /// - `export default class ...`
@@ -148,81 +298,55 @@ impl EsModuleCollector {
     ///   function ident() { ... };
     ///
     ///   // runtime_module: false
-    ///   function ident() { ... };
-    ///   export default ident;
+    ///   export default function ident() { ... };
     ///   ```
     /// - Case 2: `export default function() { ... }` to
     ///   ```js
     ///   // runtime_module: true
-    ///   const __fn = function() { ... };
+    ///   function __fn() { ... };
     ///
     ///   // runtime_module: false
-    ///   const __fn = function() { ... };
-    ///   export default __fn;
+    ///   export default function __fn() { ... };
     ///   ```
+    ///
+    /// The anonymous declaration is named to be registered while keeping its hoisting,
+    /// so its `name` property is the generated name (eg. `__fn`) instead of `default`.
     fn collect_and_convert_export_default_decl(
         &mut self,
-        export_default_decl: &mut ExportDefaultDecl,
-    ) -> Option<Stmt> {
+        export_default_decl: ExportDefaultDecl,
+    ) -> ModuleItem {
         debug!("export default decl {:#?}", export_default_decl);
+        let ExportDefaultDecl { span, decl } = export_default_decl;
 
-        match &mut export_default_decl.decl {
-            DefaultDecl::Class(class_expr) => match class_expr {
-                ClassExpr {
-                    ident: Some(class_ident),
-                    ..
-                } => {
-                    self.exports
-                        .push(ExportModule::default(class_ident.clone()));
-                    if self.runtime_module {
-                        Some(Stmt::Decl(Decl::Class(
-                            class_expr.clone().as_class_decl().unwrap(),
-                        )))
-                    } else {
-                        None
-                    }
-                }
-                ClassExpr { ident: None, .. } => {
-                    let ident = private_ident!("__Class");
-                    self.exports.push(ExportModule::default(ident.clone()));
-                    class_expr.ident = Some(ident);
-                    if self.runtime_module {
-                        Some(Stmt::Decl(Decl::Class(
-                            class_expr.clone().as_class_decl().unwrap(),
-                        )))
-                    } else {
-                        None
-                    }
-                }
-            },
-            DefaultDecl::Fn(fn_expr) => match fn_expr {
-                FnExpr {
-                    ident: Some(fn_ident),
-                    ..
-                } => {
-                    self.exports.push(ExportModule::default(fn_ident.clone()));
-                    if self.runtime_module {
-                        Some(Stmt::Decl(Decl::Fn(fn_expr.clone().as_fn_decl().unwrap())))
-                    } else {
-                        None
-                    }
+        let decl = match decl {
+            DefaultDecl::Class(mut class_expr) => {
+                let ident = class_expr
+                    .ident
+                    .get_or_insert_with(|| private_ident!(class_expr.class.span, "__Class"));
+                self.default_export_ident = Some(ident.clone());
+
+                if self.runtime_module {
+                    return self.convert_to_stmt(span, class_expr.as_class_decl().unwrap().into());
                 }
-                FnExpr { ident: None, .. } => {
-                    let ident = private_ident!("__fn");
-                    self.exports.push(ExportModule::default(ident.clone()));
-                    fn_expr.ident = Some(ident);
-                    if self.runtime_module {
-                        Some(Stmt::Decl(Decl::Fn(fn_expr.clone().as_fn_decl().unwrap())))
-                    } else {
-                        None
-                    }
+                DefaultDecl::Class(class_expr)
+            }
+            DefaultDecl::Fn(mut fn_expr) => {
+                let ident = fn_expr
+                    .ident
+                    .get_or_insert_with(|| private_ident!(fn_expr.function.span, "__fn"));
+                self.default_export_ident = Some(ident.clone());
+
+                if self.runtime_module {
+                    return self.convert_to_stmt(span, fn_expr.as_fn_decl().unwrap().into());
                 }
-            },
-            _ => None,
-        }
+                DefaultDecl::Fn(fn_expr)
+            }
+            decl => decl,
+        };
+        ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { span, decl }).into()
     }
 
-    /// Collect `ExportModule` from exports with declare statements.
+    /// Convert exports with declare statements.
     ///
     /// - `export var ...`
     /// - `export class ...`
@@ -238,277 +362,95 @@ impl EsModuleCollector {
     /// // runtime_module: false
     /// export var foo = ...;
     /// ```
-    fn collect_and_convert_export_decl(&mut self, export_decl: &ExportDecl) -> Option<Stmt> {
+    fn collect_and_convert_export_decl(&mut self, export_decl: ExportDecl) -> ModuleItem {
         debug!("export decl {:#?}", export_decl);
-        match &export_decl.decl {
+        if !self.runtime_module {
+            return ModuleDecl::ExportDecl(export_decl).into();
+        }
+
+        match export_decl.decl {
             Decl::Var(var_decl) => {
-                if let Some(var_ident) = var_decl
-                    .decls
-                    .get(0)
-                    .and_then(|var_declarator| var_declarator.name.as_ident())
-                {
-                    debug!("export decl var: {:#?}", var_ident.id.sym);
-                    self.exports
-                        .push(ExportModule::named(var_ident.id.clone(), None));
-
-                    if self.runtime_module {
-                        Some(Stmt::Decl(Decl::Var(Box::new(*var_decl.clone()))))
-                    } else {
-                        None
-                    }
-                } else {
-                    unimplemented!();
-                }
+                debug!(
+                    "export decl var: {:#?}",
+                    find_pat_ids::<_, Ident>(&var_decl.decls)
+                );
+                self.convert_to_stmt(export_decl.span, Decl::Var(var_decl))
             }
-            Decl::Fn(fn_decl @ FnDecl { ident, .. }) => {
-                debug!("export decl fn: {:#?}", ident.sym);
-                self.exports.push(ExportModule::named(ident.clone(), None));
-                if self.runtime_module {
-                    Some(Stmt::Decl(Decl::Fn(fn_decl.clone())))
-                } else {
-                    None
-                }
+            Decl::Fn(fn_decl) => {
+                debug!("export decl fn: {:#?}", fn_decl.ident.sym);
+                self.convert_to_stmt(export_decl.span, Decl::Fn(fn_decl))
             }
-            Decl::Class(class_decl @ ClassDecl { ident, .. }) => {
-                debug!("export decl class: {:#?}", ident.sym);
-                self.exports.push(ExportModule::named(ident.clone(), None));
-                if self.runtime_module {
-                    Some(Stmt::Decl(Decl::Class(class_decl.clone())))
-                } else {
-                    None
-                }
+            Decl::Class(class_decl) => {
+                debug!("export decl class: {:#?}", class_decl.ident.sym);
+                self.convert_to_stmt(export_decl.span, Decl::Class(class_decl))
             }
-            _ => None,
+            _ => ModuleDecl::ExportDecl(export_decl).into(),
         }
     }
+
+    /// Returns the declaration statement of the export declaration with its comments.
+    fn convert_to_stmt(&self, export_span: Span, decl: Decl) -> ModuleItem {
+        let stmt = Stmt::Decl(decl);
+        self.move_comments(export_span, stmt.span());
+        stmt.into()
+    }
 }
 
-impl VisitMut for EsModuleCollector {
+impl<C: Comments> VisitMut for EsModuleCollector<C> {
     noop_visit_mut_type!();
 
     fn visit_mut_module(&mut self, module: &mut Module) {
+        let mut module_info = analyze_module(module);
+        module_info.imports.retain(|info| !self.is_kept(info.span));
+        module_info
+            .bare_imports
+            .retain(|info| !self.is_kept(info.span));
+        module_info.exports.retain(|info| !self.is_kept(info.span));
+        module_info
+            .re_exports
+            .retain(|info| !self.is_kept(info.span));
+        module_info
+            .star_exports
+            .retain(|info| !self.is_kept(info.span));
         module.visit_mut_children_with(self);
+        self.collect(module_info);
     }
 
     fn visit_mut_module_items(&mut self, stmts: &mut Vec<ModuleItem>) {
-        for stmt in stmts.iter_mut() {
-            match stmt {
-                ModuleItem::ModuleDecl(module_decl) => match module_decl {
-                    ModuleDecl::Import(_) => {
-                        module_decl.visit_mut_children_with(self);
-                    }
-                    ModuleDecl::ExportDefaultExpr(export_default_expr) => {
-                        *stmt = self
-                            .collect_and_convert_export_default_expr(export_default_expr)
-                            .into();
-                    }
-                    ModuleDecl::ExportDefaultDecl(export_default_decl) => {
-                        if let Some(converted_stmt) =
-                            self.collect_and_convert_export_default_decl(export_default_decl)
-                        {
-                            *stmt = converted_stmt.into();
-                        }
-                    }
-                    ModuleDecl::ExportDecl(export_decl) => {
-                        if let Some(converted_stmt) =
-                            self.collect_and_convert_export_decl(export_decl)
-                        {
-                            *stmt = converted_stmt.into();
-                        }
-                    }
-                    _ => module_decl.visit_mut_children_with(self),
-                },
-                _ => {}
-            }
+        let mut module_items = Vec::with_capacity(stmts.len());
+        for stmt in std::mem::take(stmts) {
+            let mut stmt = match stmt {
+                ModuleItem::ModuleDecl(module_decl) if self.is_kept(module_decl.span()) => {
+                    module_items.push(module_decl.into());
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default_expr)) => {
+                    module_items
+                        .extend(self.collect_and_convert_export_default_expr(export_default_expr));
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default_decl)) => {
+                    self.collect_and_convert_export_default_decl(export_default_decl)
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                    self.collect_and_convert_export_decl(export_decl)
+                }
+                stmt => stmt,
+            };
 
             if self.runtime_module && stmt.is_module_decl() {
                 stmt.take();
             }
+            module_items.push(stmt);
         }
 
+        *stmts = module_items;
         stmts.retain(|stmt| {
             if let Some(module_decl) = stmt.as_module_decl() {
                 !is_invalid_module_decl(module_decl)
-            } else if matches!(stmt, ModuleItem::Stmt(Stmt::Empty(..))) {
-                false
             } else {
-                true
+                !matches!(stmt, ModuleItem::Stmt(Stmt::Empty(..)))
             }
         });
     }
-
-    /// Collect `ExportImport` from import statements.
-    ///
-    /// **Examples**
-    ///
-    /// - `import foo from 'src_1'`
-    /// - `import { bar, baz as baz2 } from 'src_2'`
-    ///
-    /// ---
-    ///
-    /// - Identifiers: `foo`, `bar`, `baz` with original exported name `baz`.
-    /// - Source: `src_1`, `src_2`.
-    fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
-        debug!("import decl {:#?}", import_decl);
-
-        // Collect when `runtime_module` is `true`.
-        // If non-runtime, import statements will be striped by `take()`.
-        if !self.runtime_module {
-            return;
-        }
-
-        import_decl.specifiers.iter().for_each(|import_spec| {
-            let src = import_decl.src.value.to_string();
-            match import_spec {
-                ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
-                    debug!("default import: {:#?}", local.sym);
-                    self.imports
-                        .push(ImportModule::default(local.clone(), None, src));
-                }
-                ImportSpecifier::Named(import_named_spec) => match import_named_spec {
-                    ImportNamedSpecifier {
-                        local,
-                        imported: Some(imported),
-                        ..
-                    } => match imported {
-                        ModuleExportName::Ident(imported_ident) => {
-                            debug!("named import(alias): {:#?}", local.sym);
-                            self.imports.push(ImportModule::named(
-                                local.clone(),
-                                Some(imported_ident.clone()),
-                                src,
-                            ));
-                        }
-                        ModuleExportName::Str(_) => unimplemented!(),
-                    },
-                    ImportNamedSpecifier {
-                        local,
-                        imported: None,
-                        ..
-                    } => {
-                        debug!("named import: {:#?}", local.sym);
-                        self.imports
-                            .push(ImportModule::named(local.clone(), None, src));
-                    }
-                },
-                ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
-                    debug!("namespace import: {:#?}", local.sym);
-                    self.imports
-                        .push(ImportModule::namespace(local.clone(), None, src));
-                }
-            }
-        });
-    }
-
-    /// Collect `ExportModule` from named export statements.
-    ///
-    /// (Named exports)
-    /// - `export { ... }`
-    /// - `export { ident as ... }`
-    /// - `export { default as ... }`
-    ///
-    /// (Re-exports)
-    /// - `export * as ... from '...'`
-    /// - `export { ... } from '...'`
-    fn visit_mut_named_export(&mut self, named_export: &mut NamedExport) {
-        debug!("named export {:#?}", named_export);
-        match named_export {
-            // Without source
-            // `export { ... };`
-            NamedExport {
-                src: None,
-                specifiers,
-                ..
-            } => specifiers.iter().for_each(|export_spec| {
-                if let ExportSpecifier::Named(ExportNamedSpecifier {
-                    orig: ModuleExportName::Ident(orig_ident),
-                    exported,
-                    is_type_only: false,
-                    ..
-                }) = export_spec
-                {
-                    let as_ident = exported.as_ref().map(|export_name| match export_name {
-                        ModuleExportName::Ident(as_ident) => as_ident.clone(),
-                        ModuleExportName::Str(_) => unimplemented!(),
-                    });
-                    self.exports
-                        .push(ExportModule::named(orig_ident.clone(), as_ident));
-                }
-            }),
-            // With source (re-export)
-            // Case 1: `export * as ... from '...';`
-            // Case 2: `export { ... } from '...';`
-            NamedExport {
-                src: Some(module_src),
-                specifiers,
-                ..
-            } => {
-                if let Some(ExportSpecifier::Namespace(ExportNamespaceSpecifier {
-                    name: ModuleExportName::Ident(module_ident),
-                    ..
-                })) = specifiers.get(0)
-                {
-                    // Case 1
-                    let export_ident = private_ident!("__re_export");
-                    self.imports.push(ImportModule::namespace(
-                        export_ident.clone(),
-                        None,
-                        module_src.value.to_string(),
-                    ));
-                    self.exports.push(ExportModule::named(
-                        export_ident,
-                        module_ident.clone().into(),
-                    ));
-                } else {
-                    // Case 2
-                    specifiers.iter().for_each(|import_spec| {
-                        if let ExportSpecifier::Named(ExportNamedSpecifier {
-                            orig: ModuleExportName::Ident(orig_ident),
-                            exported,
-                            ..
-                        }) = import_spec
-                        {
-                            let is_default = orig_ident.sym == "default";
-                            let ident = private_ident!("__re_export");
-                            self.imports.push(ImportModule {
-                                ident: ident.clone(),
-                                imported: Some(orig_ident.clone()),
-                                module_src: module_src.value.to_string(),
-                                module_type: if is_default {
-                                    ModuleType::DefaultAsNamed
-                                } else {
-                                    ModuleType::Named
-                                },
-                            });
-
-                            match &exported {
-                                Some(ModuleExportName::Ident(as_ident)) => self
-                                    .exports
-                                    .push(ExportModule::named(ident, as_ident.clone().into())),
-                                Some(ModuleExportName::Str(_)) => unimplemented!(),
-                                None => self
-                                    .exports
-                                    .push(ExportModule::named(ident, orig_ident.clone().into())),
-                            }
-                        }
-                    });
-                }
-            }
-        }
-    }
-
-    /// Collect `ExportModule` from export all statements.
-    ///
-    /// - `export * from ...`
-    fn visit_mut_export_all(&mut self, export_all: &mut ExportAll) {
-        debug!("export all {:#?}", export_all);
-        let export_all_ident = private_ident!("__re_export_all");
-        self.imports.push(ImportModule::namespace(
-            export_all_ident.clone(),
-            None,
-            export_all.src.value.to_string(),
-        ));
-        self.exports
-            .push(ExportModule::all(export_all_ident.clone(), None));
-    }
 }
@@ -0,0 +1,337 @@
+use crate::{
+    config::{GlobalEsmConfig, Interop},
+    constants::{
+        MODULE_ASYNC_METHOD_NAME, MODULE_EXPORT_ALL_METHOD_NAME, MODULE_EXPORT_METHOD_NAME,
+        MODULE_EXPORT_MODULE_METHOD_NAME, MODULE_HOT_METHOD_NAME, MODULE_IMPORT_METHOD_NAME,
+        MODULE_IMPORT_WILDCARD_METHOD_NAME, MODULE_INIT_METHOD_NAME,
+        MODULE_INTEROP_DEFAULT_METHOD_NAME, MODULE_INTEROP_WILDCARD_METHOD_NAME,
+        MODULE_REGISTER_LOADER_METHOD_NAME, MODULE_RESET_METHOD_NAME,
+    },
+};
+
+/// Returns the runtime script (JS) of the transformed modules.
+///
+/// Same as `runtime/index.ts`, but the registry is defined with the global and registry names
+/// of the config, and only includes the methods used by its features:
+/// `asyncModule` (`asyncMode`), `interopDefault`/`interopWildcard` (`interop`)
+/// and `hot` (`hmr`).
+pub fn generate_runtime(config: &GlobalEsmConfig) -> String {
+    let global_name = js_string(config.global_name());
+    let registry_name = js_string(config.registry_name());
+
+    let mut state = vec!["modules = {}", "loaders = {}"];
+    let mut methods = vec![reset_method(config), init_method()];
+    if config.async_mode {
+        state.push("asyncModules = {}");
+        methods.push(async_module_method());
+    }
+    if config.hmr {
+        state.push("hotModules = {}");
+    }
+    methods.extend([
+        register_loader_method(),
+        import_method(),
+        import_wildcard_method(),
+    ]);
+    if config.interop != Interop::None {
+        methods.extend([interop_default_method(), interop_wildcard_method()]);
+    }
+    methods.extend([export_method(), export_all_method(), export_module_method()]);
+    if config.hmr {
+        methods.push(hot_method());
+    }
+
+    format!(
+        r#"((globalObject, {state}) => {{
+  if (typeof globalObject === 'undefined') {{
+    throw new Error('[Global ESM] global object is undefined');
+  }}
+{HELPERS}
+  const globalEsmApi = {{
+{methods}
+  }};
+
+  Object.defineProperty(globalObject, {registry_name}, {{ value: globalEsmApi }});
+
+  // Define the global name to the global object.
+  if (!({global_name} in globalObject)) {{
+    Object.defineProperty(globalObject, {global_name}, {{ value: globalObject }});
+  }}
+}})(
+  typeof globalThis !== 'undefined'
+    ? globalThis
+    : typeof global !== 'undefined'
+    ? global
+    : typeof window !== 'undefined'
+    ? window
+    : this,
+);
+"#,
+        state = state.join(", "),
+        methods = methods.concat(),
+    )
+}
+
+/// Quote the name as a JS string literal.
+fn js_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+const HELPERS: &str = r#"
+  function getModule(moduleName, options) {
+    const attributes = options && options.with;
+    const loader = attributes && attributes.type ? loaders[attributes.type] : undefined;
+    if (attributes && loader) {
+      return loader(moduleName, attributes);
+    }
+
+    // CommonJS modules may export falsy values (eg. `module.exports = 0`).
+    const module = modules[moduleName];
+    if (module === undefined) {
+      throw new Error(`[Global ESM] "${moduleName}" module not found`);
+    }
+    return module;
+  }
+
+  function assertInitialized(moduleName) {
+    if (modules[moduleName] === undefined) {
+      throw new Error(`[Global ESM] "${moduleName}" module not initialized`);
+    }
+  }
+
+  function assertExports(moduleName, exports) {
+    assertInitialized(moduleName);
+
+    const module = modules[moduleName];
+    if (module === null || (typeof module !== 'object' && typeof module !== 'function')) {
+      throw new Error(`[Global ESM] "${moduleName}" module is not an object to export members`);
+    }
+
+    if (exports === null || (typeof exports !== 'object' && typeof exports !== 'function')) {
+      throw new Error(`[Global ESM] invalid exports argument on "${moduleName}" module registration`);
+    }
+  }
+
+  function copyMembers(module, newModule) {
+    Object.keys(module).forEach((moduleMember) => {
+      if (moduleMember !== 'default' && Object.prototype.hasOwnProperty.call(module, moduleMember)) {
+        const descriptor = Object.getOwnPropertyDescriptor(module, moduleMember);
+        if (descriptor) {
+          Object.defineProperty(newModule, moduleMember, descriptor);
+        } else {
+          newModule[moduleMember] = module[moduleMember];
+        }
+      }
+    });
+    return newModule;
+  }
+"#;
+
+fn reset_method(config: &GlobalEsmConfig) -> String {
+    let async_modules = if config.async_mode {
+        (
+            "\n        asyncModules[moduleName] = undefined;",
+            "\n        asyncModules = {};",
+        )
+    } else {
+        ("", "")
+    };
+
+    format!(
+        r#"    {MODULE_RESET_METHOD_NAME}(moduleName) {{
+      if (typeof moduleName === 'string') {{
+        modules[moduleName] = undefined;{}
+      }} else {{
+        modules = {{}};{}
+      }}
+    }},
+"#,
+        async_modules.0, async_modules.1,
+    )
+}
+
+fn init_method() -> String {
+    format!(
+        r#"    {MODULE_INIT_METHOD_NAME}(moduleName, cjs) {{
+      const module = Object.create(null);
+      if (!cjs) {{
+        Object.defineProperty(module, '__esModule', {{ value: true }});
+      }}
+      modules[moduleName] = module;
+    }},
+"#
+    )
+}
+
+fn async_module_method() -> String {
+    format!(
+        r#"    {MODULE_ASYNC_METHOD_NAME}(moduleName, dependencies, factory) {{
+      const asyncModule = Promise.all(dependencies.map((dependency) => asyncModules[dependency])).then(() => factory());
+      asyncModules[moduleName] = asyncModule;
+      return asyncModule;
+    }},
+"#
+    )
+}
+
+fn register_loader_method() -> String {
+    format!(
+        r#"    {MODULE_REGISTER_LOADER_METHOD_NAME}(type, loader) {{
+      loaders[type] = loader;
+    }},
+"#
+    )
+}
+
+fn import_method() -> String {
+    format!(
+        r#"    {MODULE_IMPORT_METHOD_NAME}(moduleName, options) {{
+      return getModule(moduleName, options);
+    }},
+"#
+    )
+}
+
+fn import_wildcard_method() -> String {
+    format!(
+        r#"    {MODULE_IMPORT_WILDCARD_METHOD_NAME}(moduleName, options) {{
+      return copyMembers(getModule(moduleName, options), Object.create(null));
+    }},
+"#
+    )
+}
+
+fn interop_default_method() -> String {
+    format!(
+        r#"    {MODULE_INTEROP_DEFAULT_METHOD_NAME}(module, nodeInterop) {{
+      return !nodeInterop && module && module.__esModule ? module : {{ default: module }};
+    }},
+"#
+    )
+}
+
+fn interop_wildcard_method() -> String {
+    format!(
+        r#"    {MODULE_INTEROP_WILDCARD_METHOD_NAME}(module, nodeInterop) {{
+      if (!nodeInterop && module && module.__esModule) {{
+        return module;
+      }}
+
+      const newModule = Object.create(null);
+      if (module !== null && (typeof module === 'object' || typeof module === 'function')) {{
+        copyMembers(module, newModule);
+      }}
+      newModule.default = module;
+      return newModule;
+    }},
+"#
+    )
+}
+
+fn export_method() -> String {
+    format!(
+        r#"    {MODULE_EXPORT_METHOD_NAME}(moduleName, exports) {{
+      assertExports(moduleName, exports);
+      Object.keys(exports).forEach((exportMember) => {{
+        if (Object.prototype.hasOwnProperty.call(exports, exportMember)) {{
+          Object.defineProperty(modules[moduleName], exportMember, {{
+            enumerable: true,
+            // CommonJS modules may register the same member more than once.
+            configurable: true,
+            get: () => exports[exportMember],
+          }});
+        }}
+      }});
+    }},
+"#
+    )
+}
+
+fn export_all_method() -> String {
+    format!(
+        r#"    {MODULE_EXPORT_ALL_METHOD_NAME}(moduleName, exports, warnAmbiguous) {{
+      const namespaces = Array.isArray(exports) ? exports : [exports];
+      namespaces.forEach((namespace) => assertExports(moduleName, namespace));
+
+      const module = modules[moduleName];
+      const localMembers = Object.keys(module);
+      const starExports = new Map();
+      const ambiguousMembers = new Set();
+
+      namespaces.forEach((namespace) => {{
+        Object.keys(namespace).forEach((exportMember) => {{
+          if (
+            exportMember === 'default' ||
+            localMembers.includes(exportMember) ||
+            !Object.prototype.hasOwnProperty.call(namespace, exportMember)
+          ) {{
+            return;
+          }}
+
+          const exportedNamespace = starExports.get(exportMember);
+          if (exportedNamespace === undefined) {{
+            starExports.set(exportMember, namespace);
+          }} else if (exportedNamespace[exportMember] !== namespace[exportMember]) {{
+            ambiguousMembers.add(exportMember);
+          }}
+        }});
+      }});
+
+      starExports.forEach((namespace, exportMember) => {{
+        if (ambiguousMembers.has(exportMember)) {{
+          if (warnAmbiguous) {{
+            console.warn(`[Global ESM] "${{exportMember}}" is ambiguous in star exports of "${{moduleName}}" module`);
+          }}
+          return;
+        }}
+
+        Object.defineProperty(module, exportMember, {{
+          enumerable: true,
+          configurable: true,
+          get: () => namespace[exportMember],
+        }});
+      }});
+    }},
+"#
+    )
+}
+
+fn export_module_method() -> String {
+    format!(
+        r#"    {MODULE_EXPORT_MODULE_METHOD_NAME}(moduleName, module) {{
+      assertInitialized(moduleName);
+      modules[moduleName] = module;
+      return module;
+    }},
+"#
+    )
+}
+
+/// The module calls `hot` once per evaluation (`const __hot = ...`), so the previous context
+/// of the module is disposed and accepts the update with the new exports.
+fn hot_method() -> String {
+    format!(
+        r#"    {MODULE_HOT_METHOD_NAME}(moduleName) {{
+      const previous = hotModules[moduleName];
+      const hotModule = {{ data: {{}}, acceptCallbacks: [], disposeCallbacks: [] }};
+      hotModules[moduleName] = hotModule;
+      if (previous) {{
+        previous.disposeCallbacks.forEach((callback) => callback(hotModule.data));
+        queueMicrotask(() => {{
+          previous.acceptCallbacks.forEach((callback) => callback(modules[moduleName]));
+        }});
+      }}
+
+      return {{
+        data: hotModule.data,
+        accept(callback) {{
+          hotModule.acceptCallbacks.push(callback || (() => {{}}));
+        }},
+        dispose(callback) {{
+          hotModule.disposeCallbacks.push(callback);
+        }},
+      }};
+    }},
+"#
+    )
+}
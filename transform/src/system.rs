@@ -0,0 +1,772 @@
+use crate::{
+    module_collector_esm::{ExportModule, ImportModule},
+    utils::{module_ident_name, obj_lit, obj_member_expr, prop_name},
+    GlobalEsmModule, ModuleType,
+};
+use swc_core::{
+    common::{
+        collections::{AHashMap, AHashSet},
+        comments::Comments,
+        util::take::Take,
+        DUMMY_SP,
+    },
+    ecma::{
+        ast::*,
+        atoms::JsWord,
+        utils::{find_pat_ids, private_ident, quote_ident, quote_str, ExprFactory},
+        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+    },
+};
+
+impl<C: Comments> GlobalEsmModule<C> {
+    /// Convert the collected module to SystemJS format.
+    ///
+    /// **Examples**
+    ///
+    /// ```js
+    /// System.register(["src"], function (_export, _context) {
+    ///   "use strict";
+    ///   var foo;
+    ///   return {
+    ///     setters: [function (_src) {
+    ///       foo = _src.default;
+    ///     }],
+    ///     execute: function () {
+    ///       // module body
+    ///       _export({ ... });
+    ///     }
+    ///   };
+    /// });
+    /// ```
+    pub(crate) fn convert_system_module(
+        &self,
+        module: &mut Module,
+        imports: &[ImportModule],
+        exports: &[ExportModule],
+        has_top_level_await: bool,
+    ) {
+        let export_ident = private_ident!("_export");
+        let context_ident = private_ident!("_context");
+
+        let mut export_names: AHashMap<Id, Vec<JsWord>> = AHashMap::default();
+        let mut star_export_idents: Vec<Ident> = Vec::new();
+        exports.iter().for_each(|export| match export.module_type {
+            ModuleType::Default | ModuleType::DefaultAsNamed => export_names
+                .entry(export.ident.to_id())
+                .or_default()
+                .push("default".into()),
+            ModuleType::Named => export_names.entry(export.ident.to_id()).or_default().push(
+                export
+                    .as_ident
+                    .as_ref()
+                    .unwrap_or(&export.ident)
+                    .sym
+                    .clone(),
+            ),
+            ModuleType::NamespaceOrAll => star_export_idents.push(export.ident.clone()),
+        });
+        let export_star_ident = private_ident!("_exportStar");
+
+        // Setters of the dependencies (actual module path, module ident, setter statements).
+        let mut deps: Vec<(String, Ident, Vec<Stmt>)> = Vec::new();
+        let mut import_idents: AHashSet<Id> = AHashSet::default();
+        imports.iter().for_each(|import| {
+            let module_src = self.config.to_actual_path(&import.module_src);
+            let index = match deps.iter().position(|(src, ..)| *src == module_src) {
+                Some(index) => index,
+                None => {
+                    let module_ident = private_ident!(module_ident_name(&module_src));
+                    deps.push((module_src, module_ident, Vec::new()));
+                    deps.len() - 1
+                }
+            };
+            let (_, module_ident, stmts) = &mut deps[index];
+            let value = match import.module_type {
+                ModuleType::Default | ModuleType::DefaultAsNamed => {
+                    obj_member_expr(module_ident.clone().into(), quote_ident!("default"))
+                }
+                ModuleType::Named => obj_member_expr(
+                    module_ident.clone().into(),
+                    quote_ident!(import
+                        .imported
+                        .as_ref()
+                        .unwrap_or(&import.ident)
+                        .sym
+                        .clone()),
+                ),
+                ModuleType::NamespaceOrAll => module_ident.clone().into(),
+            };
+            stmts.push(
+                value
+                    .make_assign_to(op!("="), import.ident.clone().as_pat_or_expr())
+                    .into_stmt(),
+            );
+
+            // Re-exported bindings are updated with the setter.
+            if star_export_idents
+                .iter()
+                .any(|ident| ident.to_id() == import.ident.to_id())
+            {
+                stmts.push(
+                    export_star_ident
+                        .clone()
+                        .as_call(DUMMY_SP, Vec::new())
+                        .into_stmt(),
+                );
+            }
+            if let Some(names) = export_names.get(&import.ident.to_id()) {
+                stmts.extend(names.iter().map(|name| {
+                    export_call_expr(&export_ident, name, import.ident.clone().into()).into_stmt()
+                }));
+            }
+            import_idents.insert(import.ident.to_id());
+        });
+
+        let reassigned_exports = exports
+            .iter()
+            .filter(|export| export.reassigned && !import_idents.contains(&export.ident.to_id()))
+            .filter_map(|export| {
+                export_names
+                    .get(&export.ident.to_id())
+                    .map(|names| (export.ident.to_id(), names.clone()))
+            })
+            .collect::<AHashMap<_, _>>();
+        let mut rewriter = ExportAssignmentRewriter {
+            export_ident: &export_ident,
+            reassigned_exports,
+            ref_ident: None,
+        };
+        module.visit_mut_with(&mut rewriter);
+
+        let (module_decls, items): (Vec<_>, Vec<_>) = std::mem::take(&mut module.body)
+            .into_iter()
+            .partition(|item| item.is_module_decl());
+        let mut execute_stmts = items
+            .into_iter()
+            .filter_map(|item| item.stmt())
+            .collect::<Vec<_>>();
+
+        // Top-level declarations are hoisted to the declaration scope, so the exported functions
+        // are available before `execute` runs (eg. circular dependencies).
+        let (fn_decls, hoisted_idents) = hoist_decls(&mut execute_stmts);
+        let fn_idents = fn_decls
+            .iter()
+            .map(|fn_decl| fn_decl.ident.to_id())
+            .collect::<AHashSet<_>>();
+        let fn_export_stmts = exports
+            .iter()
+            .filter(|export| fn_idents.contains(&export.ident.to_id()))
+            .flat_map(|export| {
+                export_names[&export.ident.to_id()].iter().map(|name| {
+                    export_call_expr(&export_ident, name, export.ident.clone().into()).into_stmt()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut local_export_props = Vec::new();
+        exports.iter().for_each(|export| {
+            if import_idents.contains(&export.ident.to_id())
+                || fn_idents.contains(&export.ident.to_id())
+                || export.module_type == ModuleType::NamespaceOrAll
+            {
+                return;
+            }
+            let name = match export.module_type {
+                ModuleType::Named => export
+                    .as_ident
+                    .as_ref()
+                    .unwrap_or(&export.ident)
+                    .sym
+                    .clone(),
+                _ => "default".into(),
+            };
+            local_export_props.push(
+                Prop::KeyValue(KeyValueProp {
+                    key: prop_name(quote_ident!(export.span, name)),
+                    value: export.ident.clone().into(),
+                })
+                .into(),
+            );
+        });
+        if !local_export_props.is_empty() {
+            execute_stmts.push(
+                export_ident
+                    .clone()
+                    .as_call(
+                        DUMMY_SP,
+                        vec![obj_lit(DUMMY_SP, Some(local_export_props)).as_arg()],
+                    )
+                    .into_stmt(),
+            );
+        }
+
+        let var_idents = imports
+            .iter()
+            .map(|import| import.ident.clone())
+            .chain(hoisted_idents)
+            .chain(rewriter.ref_ident)
+            .collect::<Vec<_>>();
+        let var_decl = (!var_idents.is_empty()).then(|| {
+            Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: var_idents
+                    .into_iter()
+                    .map(|ident| VarDeclarator {
+                        span: DUMMY_SP,
+                        name: ident.into(),
+                        init: None,
+                        definite: false,
+                    })
+                    .collect(),
+            })))
+        });
+
+        // Explicit export names are excluded from the star exports.
+        let mut explicit_export_names: Vec<JsWord> = Vec::new();
+        exports.iter().for_each(|export| {
+            if let Some(names) = export_names.get(&export.ident.to_id()) {
+                names.iter().for_each(|name| {
+                    if name != "default" && !explicit_export_names.contains(name) {
+                        explicit_export_names.push(name.clone());
+                    }
+                });
+            }
+        });
+        let mut export_star_stmts = Vec::new();
+        if !star_export_idents.is_empty() {
+            let export_names_ident = (!explicit_export_names.is_empty()).then(|| {
+                let export_names_ident = private_ident!("_exportNames");
+                export_star_stmts.push(decl_var_stmt(
+                    &export_names_ident,
+                    obj_lit(
+                        DUMMY_SP,
+                        Some(
+                            explicit_export_names
+                                .into_iter()
+                                .map(|name| {
+                                    Prop::KeyValue(KeyValueProp {
+                                        key: prop_name(quote_ident!(name)),
+                                        value: true.into(),
+                                    })
+                                    .into()
+                                })
+                                .collect(),
+                        ),
+                    ),
+                ));
+                export_names_ident
+            });
+            export_star_stmts.push(export_star_fn_decl(
+                &export_ident,
+                &export_star_ident,
+                export_names_ident.as_ref(),
+                &star_export_idents,
+            ));
+        }
+
+        let (dep_srcs, setters): (Vec<_>, Vec<_>) = deps
+            .into_iter()
+            .map(|(module_src, module_ident, stmts)| {
+                (
+                    Some(module_src.as_arg()),
+                    Some(function_expr(vec![module_ident.into()], stmts, false).as_arg()),
+                )
+            })
+            .unzip();
+
+        let declare_stmts = std::iter::once(quote_str!("use strict").into_stmt())
+            .chain(var_decl)
+            .chain(export_star_stmts)
+            .chain(
+                fn_decls
+                    .into_iter()
+                    .map(|fn_decl| Stmt::Decl(Decl::Fn(fn_decl))),
+            )
+            .chain(fn_export_stmts)
+            .chain(std::iter::once(Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(Box::new(obj_lit(
+                    DUMMY_SP,
+                    Some(vec![
+                        Prop::KeyValue(KeyValueProp {
+                            key: quote_ident!("setters").into(),
+                            value: ArrayLit {
+                                span: DUMMY_SP,
+                                elems: setters,
+                            }
+                            .into(),
+                        })
+                        .into(),
+                        Prop::KeyValue(KeyValueProp {
+                            key: quote_ident!("execute").into(),
+                            value: function_expr(Vec::new(), execute_stmts, has_top_level_await)
+                                .into(),
+                        })
+                        .into(),
+                    ]),
+                ))),
+            })))
+            .collect();
+
+        module.body = module_decls;
+        module.body.push(
+            obj_member_expr(quote_ident!("System").into(), quote_ident!("register"))
+                .as_call(
+                    DUMMY_SP,
+                    vec![
+                        ArrayLit {
+                            span: DUMMY_SP,
+                            elems: dep_srcs,
+                        }
+                        .as_arg(),
+                        function_expr(
+                            vec![export_ident.into(), context_ident.into()],
+                            declare_stmts,
+                            false,
+                        )
+                        .as_arg(),
+                    ],
+                )
+                .into_stmt()
+                .into(),
+        );
+    }
+}
+
+/// Moves the top-level function declarations out of the statements and replaces the other
+/// declarations with assignments.
+///
+/// Returns the function declarations and the idents of the replaced declarations.
+fn hoist_decls(stmts: &mut Vec<Stmt>) -> (Vec<FnDecl>, Vec<Ident>) {
+    let mut fn_decls = Vec::new();
+    let mut idents = Vec::new();
+    *stmts = std::mem::take(stmts)
+        .into_iter()
+        .flat_map(|stmt| match stmt {
+            Stmt::Decl(Decl::Fn(fn_decl)) => {
+                fn_decls.push(fn_decl);
+                Vec::new()
+            }
+            Stmt::Decl(Decl::Var(var_decl)) => var_decl
+                .decls
+                .into_iter()
+                .filter_map(|decl| {
+                    idents.extend(find_pat_ids::<_, Ident>(&decl.name));
+                    let is_object_pat = decl.name.is_object();
+                    decl.init.map(|init| {
+                        let assign_expr =
+                            init.make_assign_to(op!("="), PatOrExpr::Pat(Box::new(decl.name)));
+                        // `({ a } = obj);`
+                        if is_object_pat {
+                            assign_expr.wrap_with_paren().into_stmt()
+                        } else {
+                            assign_expr.into_stmt()
+                        }
+                    })
+                })
+                .collect(),
+            Stmt::Decl(Decl::Class(ClassDecl { ident, class, .. })) => {
+                idents.push(ident.clone());
+                vec![ClassExpr {
+                    ident: Some(ident.clone()),
+                    class,
+                }
+                .make_assign_to(op!("="), ident.as_pat_or_expr())
+                .into_stmt()]
+            }
+            stmt => vec![stmt],
+        })
+        .collect();
+    (fn_decls, idents)
+}
+
+/// Returns an anonymous function expression.
+fn function_expr(params: Vec<Pat>, stmts: Vec<Stmt>, is_async: bool) -> Expr {
+    Function {
+        params: params.into_iter().map(Param::from).collect(),
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts,
+        }),
+        is_async,
+        ..Take::dummy()
+    }
+    .into()
+}
+
+/// Returns `var ident = init` statement.
+fn decl_var_stmt(ident: &Ident, init: Expr) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Var,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: ident.clone().into(),
+            init: Some(Box::new(init)),
+            definite: false,
+        }],
+    })))
+}
+
+/// Returns `_export(name, value)` expression.
+fn export_call_expr(export_ident: &Ident, name: &JsWord, value: Expr) -> Expr {
+    export_ident.clone().as_call(
+        DUMMY_SP,
+        vec![quote_str!(name.clone()).as_arg(), value.as_arg()],
+    )
+}
+
+/// Returns the function that exports the members of the star exported namespaces.
+///
+/// Same as the runtime `exportAll`, `default`, the explicit export names of the module and
+/// the ambiguous names (exported with different values by more than one namespace) are excluded.
+/// The members are exported once all the namespaces are set to find the ambiguous names.
+///
+/// ```js
+/// function _exportStar() {
+///   var _namespaces = [ns_1, ns_2];
+///   if (_namespaces.every(Boolean)) _namespaces.forEach(function (_ns) {
+///     for (var _key in _ns)
+///       if (_key !== "default" && _exportNames[_key] !== true && _namespaces.every(function (_other) {
+///         return !(_key in _other) || _other[_key] === _ns[_key];
+///       })) _export(_key, _ns[_key]);
+///   });
+/// }
+/// ```
+fn export_star_fn_decl(
+    export_ident: &Ident,
+    export_star_ident: &Ident,
+    export_names_ident: Option<&Ident>,
+    star_idents: &[Ident],
+) -> Stmt {
+    let namespaces_ident = private_ident!("_namespaces");
+    let ns_ident = private_ident!("_ns");
+    let key_ident = private_ident!("_key");
+    let other_ident = private_ident!("_other");
+    let bin_expr = |op, left: Expr, right: Expr| {
+        Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+    };
+    let namespaces_call = |method: &str, callback: Expr| {
+        namespaces_ident
+            .clone()
+            .make_member(quote_ident!(method))
+            .as_call(DUMMY_SP, vec![callback.as_arg()])
+    };
+
+    let mut conditions = vec![bin_expr(
+        op!("!=="),
+        key_ident.clone().into(),
+        quote_str!("default").into(),
+    )];
+    if let Some(export_names_ident) = export_names_ident {
+        conditions.push(bin_expr(
+            op!("!=="),
+            export_names_ident
+                .clone()
+                .computed_member(key_ident.clone()),
+            true.into(),
+        ));
+    }
+    if star_idents.len() > 1 {
+        let is_same_value = bin_expr(
+            op!("||"),
+            Expr::Unary(UnaryExpr {
+                span: DUMMY_SP,
+                op: op!("!"),
+                arg: Box::new(
+                    bin_expr(
+                        op!("in"),
+                        key_ident.clone().into(),
+                        other_ident.clone().into(),
+                    )
+                    .wrap_with_paren(),
+                ),
+            }),
+            bin_expr(
+                op!("==="),
+                other_ident.clone().computed_member(key_ident.clone()),
+                ns_ident.clone().computed_member(key_ident.clone()),
+            ),
+        );
+        conditions.push(namespaces_call(
+            "every",
+            function_expr(
+                vec![other_ident.into()],
+                vec![Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(Box::new(is_same_value)),
+                })],
+                false,
+            ),
+        ));
+    }
+
+    let for_in_stmt = Stmt::ForIn(ForInStmt {
+        span: DUMMY_SP,
+        left: ForHead::VarDecl(Box::new(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: key_ident.clone().into(),
+                init: None,
+                definite: false,
+            }],
+        })),
+        right: Box::new(ns_ident.clone().into()),
+        body: Box::new(Stmt::If(IfStmt {
+            span: DUMMY_SP,
+            test: Box::new(
+                conditions
+                    .into_iter()
+                    .reduce(|left, right| bin_expr(op!("&&"), left, right))
+                    .unwrap(),
+            ),
+            cons: Box::new(
+                export_ident
+                    .clone()
+                    .as_call(
+                        DUMMY_SP,
+                        vec![
+                            key_ident.clone().as_arg(),
+                            ns_ident.clone().computed_member(key_ident).as_arg(),
+                        ],
+                    )
+                    .into_stmt(),
+            ),
+            alt: None,
+        })),
+    });
+
+    let stmts = vec![
+        decl_var_stmt(
+            &namespaces_ident,
+            ArrayLit {
+                span: DUMMY_SP,
+                elems: star_idents
+                    .iter()
+                    .map(|ident| Some(ident.clone().as_arg()))
+                    .collect(),
+            }
+            .into(),
+        ),
+        Stmt::If(IfStmt {
+            span: DUMMY_SP,
+            test: Box::new(namespaces_call("every", quote_ident!("Boolean").into())),
+            cons: Box::new(
+                namespaces_call(
+                    "forEach",
+                    function_expr(vec![ns_ident.into()], vec![for_in_stmt], false),
+                )
+                .into_stmt(),
+            ),
+            alt: None,
+        }),
+    ];
+
+    Stmt::Decl(Decl::Fn(FnDecl {
+        ident: export_star_ident.clone(),
+        declare: false,
+        function: Box::new(Function {
+            body: Some(BlockStmt {
+                span: DUMMY_SP,
+                stmts,
+            }),
+            ..Take::dummy()
+        }),
+    }))
+}
+
+/// Wraps assignments of reassigned exports with `_export(name, value)` to update live bindings.
+///
+/// - `count = 1` to `_export("count", count = 1)`
+/// - `++count` to `_export("count", ++count)`
+/// - `count++` to `(_export("count", +count + 1), count++)`
+/// - `[count] = list` to `(_ref = [count] = list, _export("count", count), _ref)`
+/// - `for (count of list) body` to `for (count of list) { _export("count", count); body }`
+struct ExportAssignmentRewriter<'a> {
+    export_ident: &'a Ident,
+    reassigned_exports: AHashMap<Id, Vec<JsWord>>,
+    // Holds the value of destructuring assignments, declared in the declaration scope.
+    ref_ident: Option<Ident>,
+}
+
+impl ExportAssignmentRewriter<'_> {
+    fn wrap(&self, names: &[JsWord], value: Expr) -> Expr {
+        names.iter().fold(value, |value, name| {
+            export_call_expr(self.export_ident, name, value)
+        })
+    }
+
+    /// Returns `_export(name, ident)` expressions of the exported bindings in the pattern.
+    fn pat_export_exprs(&self, pat: &Pat) -> Vec<Expr> {
+        let idents = match pat {
+            Pat::Expr(expr) => expr.as_ident().cloned().into_iter().collect(),
+            _ => find_pat_ids::<_, Ident>(pat),
+        };
+        idents
+            .into_iter()
+            .flat_map(|ident| {
+                self.reassigned_exports
+                    .get(&ident.to_id())
+                    .into_iter()
+                    .flatten()
+                    .map(move |name| {
+                        export_call_expr(self.export_ident, name, ident.clone().into())
+                    })
+            })
+            .collect()
+    }
+
+    /// Prepend `_export(name, ident)` statements of the `for-in/of` head to the loop body.
+    fn export_for_head(&self, left: &ForHead, body: &mut Box<Stmt>) {
+        let ForHead::Pat(pat) = left else {
+            return;
+        };
+        let export_exprs = self.pat_export_exprs(pat);
+        if export_exprs.is_empty() {
+            return;
+        }
+
+        let mut stmts = export_exprs
+            .into_iter()
+            .map(|expr| expr.into_stmt())
+            .collect::<Vec<_>>();
+        match *body.take() {
+            Stmt::Block(block) => stmts.extend(block.stmts),
+            stmt => stmts.push(stmt),
+        }
+        **body = Stmt::Block(BlockStmt {
+            span: DUMMY_SP,
+            stmts,
+        });
+    }
+}
+
+impl VisitMut for ExportAssignmentRewriter<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        match expr {
+            Expr::Assign(AssignExpr { left, .. }) => {
+                let ident = match left {
+                    PatOrExpr::Pat(pat) => match &**pat {
+                        Pat::Ident(BindingIdent { id, .. }) => Some(id),
+                        Pat::Expr(expr) => expr.as_ident(),
+                        pat => {
+                            let export_exprs = self.pat_export_exprs(pat);
+                            if !export_exprs.is_empty() {
+                                let ref_ident = self
+                                    .ref_ident
+                                    .get_or_insert_with(|| private_ident!("_ref"))
+                                    .clone();
+                                let mut exprs =
+                                    vec![Box::new(expr.take().make_assign_to(
+                                        op!("="),
+                                        ref_ident.clone().as_pat_or_expr(),
+                                    ))];
+                                exprs.extend(export_exprs.into_iter().map(Box::new));
+                                exprs.push(Box::new(ref_ident.into()));
+                                *expr = Expr::Seq(SeqExpr {
+                                    span: DUMMY_SP,
+                                    exprs,
+                                });
+                            }
+                            return;
+                        }
+                    },
+                    PatOrExpr::Expr(expr) => expr.as_ident(),
+                };
+                if let Some(names) = ident.and_then(|id| self.reassigned_exports.get(&id.to_id())) {
+                    let names = names.clone();
+                    *expr = self.wrap(&names, expr.take());
+                }
+            }
+            Expr::Update(UpdateExpr {
+                op, prefix, arg, ..
+            }) => {
+                let Some(ident) = arg.as_ident() else {
+                    return;
+                };
+                let Some(names) = self.reassigned_exports.get(&ident.to_id()) else {
+                    return;
+                };
+                let names = names.clone();
+                if *prefix {
+                    *expr = self.wrap(&names, expr.take());
+                } else {
+                    // The updated value is numeric (eg. `"1"++` is `2`).
+                    let value = Expr::Bin(BinExpr {
+                        span: DUMMY_SP,
+                        op: if *op == op!("++") {
+                            op!(bin, "+")
+                        } else {
+                            op!(bin, "-")
+                        },
+                        left: Box::new(Expr::Unary(UnaryExpr {
+                            span: DUMMY_SP,
+                            op: op!(unary, "+"),
+                            arg: Box::new(ident.clone().into()),
+                        })),
+                        right: 1.0.into(),
+                    });
+                    *expr = Expr::Seq(SeqExpr {
+                        span: DUMMY_SP,
+                        exprs: vec![Box::new(self.wrap(&names, value)), Box::new(expr.take())],
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_mut_expr_stmt(&mut self, expr_stmt: &mut ExprStmt) {
+        // The value of destructuring assignment statements is unused.
+        //
+        // `[count] = list;` to `[count] = list, _export("count", count);`
+        let mut expr = &mut *expr_stmt.expr;
+        while let Expr::Paren(ParenExpr { expr: inner, .. }) = expr {
+            expr = &mut **inner;
+        }
+        if let Expr::Assign(assign_expr) = expr {
+            let export_exprs = match &assign_expr.left {
+                PatOrExpr::Pat(pat) if !matches!(**pat, Pat::Ident(_) | Pat::Expr(_)) => {
+                    self.pat_export_exprs(pat)
+                }
+                _ => Vec::new(),
+            };
+            if !export_exprs.is_empty() {
+                assign_expr.visit_mut_children_with(self);
+                let mut exprs = vec![expr_stmt.expr.take()];
+                exprs.extend(export_exprs.into_iter().map(Box::new));
+                *expr_stmt.expr = Expr::Seq(SeqExpr {
+                    span: DUMMY_SP,
+                    exprs,
+                });
+                return;
+            }
+        }
+        expr_stmt.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_for_in_stmt(&mut self, for_in_stmt: &mut ForInStmt) {
+        for_in_stmt.visit_mut_children_with(self);
+        self.export_for_head(&for_in_stmt.left, &mut for_in_stmt.body);
+    }
+
+    fn visit_mut_for_of_stmt(&mut self, for_of_stmt: &mut ForOfStmt) {
+        for_of_stmt.visit_mut_children_with(self);
+        self.export_for_head(&for_of_stmt.left, &mut for_of_stmt.body);
+    }
+}
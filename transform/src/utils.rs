@@ -1,30 +1,91 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
 use swc_core::{
-    common::DUMMY_SP,
+    common::{collections::AHashSet, comments::Comments, BytePos, Span, DUMMY_SP},
     ecma::{
         ast::*,
         utils::{quote_ident, ExprFactory},
+        visit::{noop_visit_type, Visit, VisitWith},
     },
 };
 
-use crate::constants::{GLOBAL, MODULE};
+/// Returns the ident name of the module source.
+///
+/// The pattern is compiled once and shared by all transforms (eg. files transformed in parallel).
+///
+/// eg. `@app/core` -> `__app_core`
+pub fn module_ident_name(module_src: &str) -> String {
+    static NORMALIZE_REGEX: OnceLock<Regex> = OnceLock::new();
+    NORMALIZE_REGEX
+        .get_or_init(|| Regex::new(r"[^a-zA-Z0-9]").unwrap())
+        .replace_all(format!("_{module_src}").as_str(), "_")
+        .to_string()
+}
+
+/// Check the name is a valid identifier name (reserved words are allowed as member keys).
+pub fn is_ident_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(Ident::is_valid_start) && chars.all(Ident::is_valid_continue)
+}
 
 /// Returns an object member expression.
 ///
 /// eg. `obj.prop`
+/// eg. `obj["a-b"]` (string import and export names)
 pub fn obj_member_expr(obj: Expr, prop: Ident) -> Expr {
     Expr::Member(MemberExpr {
         span: DUMMY_SP,
         obj: obj.into(),
-        prop: prop.into(),
+        prop: if is_ident_name(&prop.sym) {
+            prop.into()
+        } else {
+            MemberProp::Computed(ComputedPropName {
+                span: prop.span,
+                expr: Box::new(Str::from(prop.sym).into()),
+            })
+        },
     })
 }
 
+/// Returns a property name of the object literal.
+///
+/// eg. `prop`
+/// eg. `"a-b"` (string export names)
+pub fn prop_name(name: Ident) -> PropName {
+    if is_ident_name(&name.sym) {
+        name.into()
+    } else {
+        PropName::Str(Str {
+            span: name.span,
+            value: name.sym,
+            raw: None,
+        })
+    }
+}
+
+/// Returns the import or export name of the module declaration.
+///
+/// eg. `a` in `import { a as b } from 'src'`
+/// eg. `'a-b'` in `export { a as 'a-b' }`
+pub fn module_export_name(name: Ident) -> ModuleExportName {
+    if is_ident_name(&name.sym) {
+        ModuleExportName::Ident(name)
+    } else {
+        ModuleExportName::Str(Str {
+            span: name.span,
+            value: name.sym,
+            raw: None,
+        })
+    }
+}
+
 /// Returns an assign expression with declare variable statement.
 ///
 /// eg. `const name = expr`
-pub fn decl_var_and_assign_stmt(ident: &Ident, init: Expr) -> Stmt {
+pub fn decl_var_and_assign_stmt(span: Span, ident: &Ident, init: Expr) -> Stmt {
     Stmt::Decl(Decl::Var(Box::new(VarDecl {
-        span: DUMMY_SP,
+        span,
         kind: VarDeclKind::Const,
         declare: false,
         decls: vec![VarDeclarator {
@@ -39,23 +100,67 @@ pub fn decl_var_and_assign_stmt(ident: &Ident, init: Expr) -> Stmt {
 /// Returns an object literal expression.
 ///
 /// eg. `{ props }`
-pub fn obj_lit(props: Option<Vec<PropOrSpread>>) -> Expr {
+pub fn obj_lit(span: Span, props: Option<Vec<PropOrSpread>>) -> Expr {
     ObjectLit {
-        span: DUMMY_SP,
-        props: props.unwrap_or(Vec::new()),
+        span,
+        props: props.unwrap_or_default(),
     }
     .into()
 }
 
-/// Returns a global module api method call expression.
+/// Returns a getter property that returns the current value of the binding.
 ///
-/// eg. `global.__modules.{method_name}(...args)`
-pub fn global_module_api_call_expr(method_name: &str, args: Vec<ExprOrSpread>) -> Expr {
+/// eg. `get key() { return ident; }`
+pub fn getter_prop(span: Span, key: Ident, ident: Ident) -> Prop {
+    Prop::Getter(GetterProp {
+        span,
+        key: prop_name(key),
+        type_ann: None,
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(ident.into()),
+            })],
+        }),
+    })
+}
+
+/// Returns the module registry expression.
+///
+/// eg. `global.__modules`
+pub fn registry_expr(global_name: &str, registry_name: &str) -> Expr {
     obj_member_expr(
-        obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE).into()),
-        quote_ident!(method_name),
+        quote_ident!(global_name).into(),
+        quote_ident!(registry_name),
     )
-    .as_call(DUMMY_SP, args)
+}
+
+/// Returns a global module api method call expression.
+///
+/// eg. `global.__modules.{method_name}(...args)`
+pub fn global_module_api_call_expr(
+    registry: Expr,
+    span: Span,
+    method_name: &str,
+    args: Vec<ExprOrSpread>,
+) -> Expr {
+    obj_member_expr(registry, quote_ident!(method_name)).as_call(span, args)
+}
+
+/// Returns a global module api method call statement.
+///
+/// eg. `global.__modules.{method_name}(...args);`
+pub fn global_module_api_call_stmt(
+    registry: Expr,
+    span: Span,
+    method_name: &str,
+    args: Vec<ExprOrSpread>,
+) -> Stmt {
+    Stmt::Expr(ExprStmt {
+        span,
+        expr: global_module_api_call_expr(registry, span, method_name, args).into(),
+    })
 }
 
 /// Check `ModuleDecl` is invalid.
@@ -69,3 +174,104 @@ pub fn is_invalid_module_decl(module_decl: &ModuleDecl) -> bool {
         false
     }
 }
+
+/// Check the leading comments at `pos` contain the pragma.
+///
+/// eg. `// @global-esm-ignore`, `/* @global-esm-keep */`
+pub fn has_leading_pragma<C: Comments>(comments: &C, pos: BytePos, pragma: &str) -> bool {
+    comments.with_leading(pos, |comments| {
+        comments
+            .iter()
+            .any(|comment| comment.text.split_whitespace().any(|word| word == pragma))
+    })
+}
+
+/// Returns a key of import attributes to distinguish imports of the same module.
+///
+/// eg. `type=json` for `with { type: "json" }`
+pub fn import_attributes_key(with: &ObjectLit) -> String {
+    with.props
+        .iter()
+        .filter_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(Ident { sym: key, .. }) | PropName::Str(Str { value: key, .. }),
+                    value,
+                }) => match &**value {
+                    Expr::Lit(Lit::Str(Str { value, .. })) => Some(format!("{key}={value}")),
+                    _ => None,
+                },
+                _ => None,
+            },
+            PropOrSpread::Spread(_) => None,
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Collects idents that are referenced in the node.
+///
+/// Property names and types (eg. elided by TypeScript) are not references.
+pub struct UsedIdentsCollector {
+    used: AHashSet<Id>,
+}
+
+impl Visit for UsedIdentsCollector {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.used.insert(ident.to_id());
+    }
+
+    fn visit_member_prop(&mut self, prop: &MemberProp) {
+        if let MemberProp::Computed(computed) = prop {
+            computed.visit_with(self);
+        }
+    }
+
+    fn visit_super_prop(&mut self, prop: &SuperProp) {
+        if let SuperProp::Computed(computed) = prop {
+            computed.visit_with(self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, prop_name: &PropName) {
+        if let PropName::Computed(computed) = prop_name {
+            computed.visit_with(self);
+        }
+    }
+}
+
+/// Returns idents that are referenced in the node.
+pub fn used_idents<N: VisitWith<UsedIdentsCollector>>(node: &N) -> AHashSet<Id> {
+    let mut collector = UsedIdentsCollector {
+        used: AHashSet::default(),
+    };
+    node.visit_with(&mut collector);
+    collector.used
+}
+
+/// Collects whether the node contains JSX elements or fragments.
+#[derive(Default)]
+pub struct JsxCollector {
+    found: bool,
+}
+
+impl Visit for JsxCollector {
+    noop_visit_type!();
+
+    fn visit_jsx_element(&mut self, _: &JSXElement) {
+        self.found = true;
+    }
+
+    fn visit_jsx_fragment(&mut self, _: &JSXFragment) {
+        self.found = true;
+    }
+}
+
+/// Check the node contains JSX elements or fragments.
+pub fn contains_jsx<N: VisitWith<JsxCollector>>(node: &N) -> bool {
+    let mut collector = JsxCollector::default();
+    node.visit_with(&mut collector);
+    collector.found
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use common::parse;
+use swc_global_esm::{analyze_module, ModuleType};
+
+#[test]
+fn analyze_imports() {
+    let module = parse(
+        r#"
+        import React, { useState, useEffect as effect } from 'react';
+        import * as app from '@app/core';
+        import type { Props } from './types';
+        import './polyfill';
+        "#,
+    );
+    let module_info = analyze_module(&module);
+    let imports = module_info
+        .imports
+        .iter()
+        .map(|import| {
+            (
+                import.ident.sym.to_string(),
+                import.imported.as_ref().map(|ident| ident.sym.to_string()),
+                import.module_src.as_str(),
+                import.module_type,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        imports,
+        vec![
+            (String::from("React"), None, "react", ModuleType::Default),
+            (String::from("useState"), None, "react", ModuleType::Named),
+            (
+                String::from("effect"),
+                Some(String::from("useEffect")),
+                "react",
+                ModuleType::Named
+            ),
+            (
+                String::from("app"),
+                None,
+                "@app/core",
+                ModuleType::NamespaceOrAll
+            ),
+        ]
+    );
+    assert_eq!(
+        module_info
+            .bare_imports
+            .iter()
+            .map(|bare_import| bare_import.module_src.as_str())
+            .collect::<Vec<_>>(),
+        vec!["./polyfill"]
+    );
+    assert_eq!(
+        module_info.module_sources(),
+        vec!["react", "@app/core", "./polyfill"]
+    );
+}
+
+#[test]
+fn analyze_exports() {
+    let module = parse(
+        r#"
+        export const a = 1, { b, c: [d] } = {};
+        export function fn() {}
+        export default class {}
+        export { a as e };
+        export * as ns from 'mod_a';
+        export { default as f, g } from 'mod_b';
+        export * from 'mod_c';
+        "#,
+    );
+    let module_info = analyze_module(&module);
+
+    assert_eq!(
+        module_info
+            .exports
+            .iter()
+            .map(|export| (
+                export.ident.as_ref().map(|ident| ident.sym.to_string()),
+                export.name.to_string(),
+                export.module_type
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                Some(String::from("a")),
+                String::from("a"),
+                ModuleType::Named
+            ),
+            (
+                Some(String::from("b")),
+                String::from("b"),
+                ModuleType::Named
+            ),
+            (
+                Some(String::from("d")),
+                String::from("d"),
+                ModuleType::Named
+            ),
+            (
+                Some(String::from("fn")),
+                String::from("fn"),
+                ModuleType::Named
+            ),
+            (None, String::from("default"), ModuleType::Default),
+            (
+                Some(String::from("a")),
+                String::from("e"),
+                ModuleType::Named
+            ),
+        ]
+    );
+    assert_eq!(
+        module_info
+            .re_exports
+            .iter()
+            .map(|re_export| (
+                re_export.name.to_string(),
+                re_export.module_src.as_str(),
+                re_export.module_type
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (String::from("ns"), "mod_a", ModuleType::NamespaceOrAll),
+            (String::from("f"), "mod_b", ModuleType::DefaultAsNamed),
+            (String::from("g"), "mod_b", ModuleType::Named),
+        ]
+    );
+    assert_eq!(
+        module_info
+            .star_exports
+            .iter()
+            .map(|star_export| star_export.module_src.as_str())
+            .collect::<Vec<_>>(),
+        vec!["mod_c"]
+    );
+    assert_eq!(
+        module_info.export_names(),
+        vec!["a", "b", "d", "fn", "default", "e", "ns", "f", "g"]
+    );
+}
+
+#[test]
+fn analyze_reassigned_exports() {
+    let module = parse(
+        r#"
+        export let count = 0, other = 0;
+        export const LIMIT = 10;
+        let value;
+        for (value of []) {}
+        export function increase() {
+            count++;
+            let other = 1;
+            other = 2;
+        }
+        export { value };
+        "#,
+    );
+    let module_info = analyze_module(&module);
+
+    assert_eq!(
+        module_info
+            .exports
+            .iter()
+            .map(|export| (export.name.to_string(), export.reassigned))
+            .collect::<Vec<_>>(),
+        vec![
+            (String::from("count"), true),
+            // Without resolver, shadowed bindings share the same context.
+            (String::from("other"), true),
+            (String::from("LIMIT"), false),
+            (String::from("increase"), false),
+            (String::from("value"), true),
+        ]
+    );
+}
+
+#[test]
+fn analyze_top_level_await() {
+    let has_top_level_await = |code: &str| analyze_module(&parse(code)).has_top_level_await;
+
+    assert!(has_top_level_await("const config = await load();"));
+    assert!(has_top_level_await("for await (const chunk of stream) {}"));
+    assert!(!has_top_level_await(
+        "async function load() { await fetch(); }"
+    ));
+    assert!(!has_top_level_await(
+        "const load = async () => await fetch();"
+    ));
+}
@@ -0,0 +1,83 @@
+use swc_core::{
+    common::{comments::NoopComments, sync::Lrc, FileName, Globals, SourceMap, GLOBALS},
+    ecma::{ast::*, visit::VisitMutWith},
+};
+use swc_ecma_parser::{parse_file_as_script, Syntax};
+use swc_global_esm::{global_esm, GlobalEsmConfig};
+
+fn parse_script(code: &str) -> Script {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon, code.into());
+    parse_file_as_script(
+        &fm,
+        Syntax::Es(Default::default()),
+        Default::default(),
+        None,
+        &mut vec![],
+    )
+    .expect("failed to parse script")
+}
+
+/// Returns the method name of `global.__modules.method(...)` statements.
+fn global_module_api_methods(script: &Script) -> Vec<String> {
+    script
+        .body
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+                Expr::Call(CallExpr {
+                    callee: Callee::Expr(callee),
+                    ..
+                }) => match &**callee {
+                    Expr::Member(MemberExpr {
+                        prop: MemberProp::Ident(method),
+                        ..
+                    }) => Some(method.sym.to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+fn transform_script(code: &str, cjs: bool) -> Script {
+    let mut script = parse_script(code);
+    GLOBALS.set(&Globals::new(), || {
+        script.visit_mut_with(&mut global_esm(
+            String::from("test.js"),
+            GlobalEsmConfig {
+                runtime_module: true,
+                cjs,
+                ..Default::default()
+            },
+            NoopComments,
+        ));
+    });
+    script
+}
+
+#[test]
+fn convert_cjs_script() {
+    let script = transform_script(
+        r#"
+        const React = require('react');
+        module.exports = React;
+        exports.value = 1;
+        "#,
+        true,
+    );
+
+    assert_eq!(
+        global_module_api_methods(&script),
+        vec!["init", "exportModule", "export"]
+    );
+}
+
+#[test]
+fn keep_script_without_cjs_option() {
+    let script = transform_script("module.exports = require('react');", false);
+
+    assert!(global_module_api_methods(&script).is_empty());
+}
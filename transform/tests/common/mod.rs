@@ -0,0 +1,16 @@
+use swc_core::common::{sync::Lrc, FileName, SourceMap};
+use swc_core::ecma::ast::Module;
+use swc_ecma_parser::{parse_file_as_module, Syntax, TsConfig};
+
+pub fn parse(code: &str) -> Module {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon, code.into());
+    parse_file_as_module(
+        &fm,
+        Syntax::Typescript(TsConfig::default()),
+        Default::default(),
+        None,
+        &mut vec![],
+    )
+    .expect("failed to parse module")
+}
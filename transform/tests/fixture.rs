@@ -1,12 +1,53 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
+use swc_core::{
+    common::{chain, Mark, Span},
+    ecma::{
+        ast::*,
+        utils::{quote_ident, ExprFactory},
+    },
+};
 use swc_ecma_parser::{Syntax, TsConfig};
+use swc_ecma_transforms_base::resolver;
 use swc_ecma_transforms_testing::test_fixture;
-use swc_global_esm::global_esm;
+use swc_global_esm::{
+    global_esm, global_esm_late_imports, global_esm_with_adapter, GlobalEsmConfig, Interop,
+    RuntimeAdapter, Target, Wrap,
+};
+
+/// Registers modules to `__registry` instead of `global.__modules`.
+struct RegistryAdapter;
+
+impl RegistryAdapter {
+    fn call(span: Span, method: &str, args: Vec<ExprOrSpread>) -> Expr {
+        quote_ident!("__registry")
+            .make_member(quote_ident!(method))
+            .as_call(span, args)
+    }
+}
+
+impl RuntimeAdapter for RegistryAdapter {
+    fn import(&self, span: Span, module_src: &str, _with: Option<&ObjectLit>) -> Expr {
+        Self::call(span, "require", vec![module_src.as_arg()])
+    }
+
+    fn import_wildcard(&self, span: Span, module_src: &str, with: Option<&ObjectLit>) -> Expr {
+        self.import(span, module_src, with)
+    }
+
+    fn export(&self, span: Span, module_name: &str, exports: Expr) -> Expr {
+        Self::call(span, "define", vec![module_name.as_arg(), exports.as_arg()])
+    }
+
+    fn dynamic_import(&self, span: Span, module_src: &str) -> Option<Expr> {
+        Some(Self::call(span, "load", vec![module_src.as_arg()]))
+    }
+}
 
 #[testing::fixture("tests/fixture/import/**/input.js")]
 #[testing::fixture("tests/fixture/export/**/input.js")]
 #[testing::fixture("tests/fixture/normalize_src/**/input.js")]
+#[testing::fixture("tests/fixture/pragma/**/input.js")]
 fn fixture(input: PathBuf) {
     let filename = input.to_string_lossy();
     let output = input.with_file_name("output.js");
@@ -16,7 +57,16 @@ fn fixture(input: PathBuf) {
             tsx: filename.ends_with(".tsx"),
             ..Default::default()
         }),
-        &|_| global_esm(String::from("test.js"), true, None),
+        &|t| {
+            global_esm(
+                String::from("test.js"),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
         &input,
         &output,
         Default::default(),
@@ -33,7 +83,13 @@ fn fixture_non_runtime(input: PathBuf) {
             tsx: filename.ends_with(".tsx"),
             ..Default::default()
         }),
-        &|_| global_esm(String::from("test.js"), false, None),
+        &|t| {
+            global_esm(
+                String::from("test.js"),
+                GlobalEsmConfig::default(),
+                t.comments.clone(),
+            )
+        },
         &input,
         &output,
         Default::default(),
@@ -54,7 +110,320 @@ fn fixture_import_paths(input: PathBuf) {
             tsx: filename.ends_with(".tsx"),
             ..Default::default()
         }),
-        &|_| global_esm(String::from("test.js"), true, Some(import_paths.to_owned())),
+        &|t| {
+            global_esm(
+                String::from("test.js"),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    import_paths: Some(import_paths.to_owned()),
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/interop/babel/input.js")]
+#[testing::fixture("tests/fixture/interop/node/input.js")]
+fn fixture_interop(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+    let interop = if filename.contains("/node/") {
+        Interop::Node
+    } else {
+        Interop::Babel
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            global_esm(
+                String::from("test.js"),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    interop,
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/late_imports/input.js")]
+#[testing::fixture("tests/fixture/late_imports/keep/input.js")]
+#[testing::fixture("tests/fixture/late_imports/directive/input.js")]
+fn fixture_late_imports(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| global_esm_late_imports(GlobalEsmConfig::default(), t.comments.clone()),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/late_imports/wrap/**/input.js")]
+fn fixture_late_imports_wrap(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            global_esm_late_imports(
+                GlobalEsmConfig {
+                    wrap: Wrap::Iife,
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/late_imports/async/input.js")]
+fn fixture_late_imports_async(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            global_esm_late_imports(
+                GlobalEsmConfig {
+                    async_mode: true,
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/async/**/input.js")]
+fn fixture_async(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            global_esm(
+                String::from("test.js"),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    async_mode: true,
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/system/**/input.js")]
+fn fixture_system(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            global_esm(
+                String::from("test.js"),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    target: Target::SystemJs,
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/cjs/**/input.js")]
+fn fixture_cjs(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            chain!(
+                resolver(Mark::new(), Mark::new(), false),
+                global_esm(
+                    String::from("test.js"),
+                    GlobalEsmConfig {
+                        runtime_module: true,
+                        cjs: true,
+                        ..Default::default()
+                    },
+                    t.comments.clone(),
+                )
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/adapter/input.js")]
+fn fixture_adapter(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            global_esm_with_adapter(
+                String::from("test.js"),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    ..Default::default()
+                },
+                t.comments.clone(),
+                Arc::new(RegistryAdapter),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/wrap/**/input.js")]
+fn fixture_wrap(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+    let wrap = if filename.contains("block") {
+        Wrap::Block
+    } else {
+        Wrap::Iife
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            global_esm(
+                String::from("test.js"),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    wrap,
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/hmr/**/input.js")]
+fn fixture_hmr(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            global_esm(
+                String::from("test.js"),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    hmr: true,
+                    global_name: Some(String::from("app")),
+                    registry_name: Some(String::from("__app_modules")),
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/unused_imports/**/input.js")]
+#[testing::fixture("tests/fixture/unused_imports/**/input.tsx")]
+fn fixture_unused_imports(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        Syntax::Typescript(TsConfig {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|t| {
+            global_esm(
+                String::from("test.js"),
+                GlobalEsmConfig {
+                    runtime_module: true,
+                    remove_unused_imports: true,
+                    side_effects: vec![String::from("./polyfill"), String::from("./side-effect")],
+                    ..Default::default()
+                },
+                t.comments.clone(),
+            )
+        },
         &input,
         &output,
         Default::default(),
@@ -0,0 +1,12 @@
+import React from 'react';
+import * as core from '@app/core';
+import { useState } from 'react';
+
+export const lazy = () => import('./lazy');
+
+export default function App() {
+  const [count] = useState(0);
+  return React.createElement(core.View, null, count);
+}
+
+export * from '@app/components';
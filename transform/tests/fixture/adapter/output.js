@@ -0,0 +1,18 @@
+const _react = __registry.require("react");
+const React = _react.default;
+const core = __registry.require("@app/core");
+const useState = _react.useState;
+const __re_export_all = __registry.require("@app/components");
+const lazy = ()=>__registry.load("./lazy");
+function App() {
+  const [count] = useState(0);
+  return React.createElement(core.View, null, count);
+}
+global.__modules.init("test.js");
+__registry.define("test.js", {
+  lazy,
+  default: App
+});
+global.__modules.exportAll("test.js", [
+  __re_export_all
+]);
@@ -0,0 +1,4 @@
+import './a';
+import { b } from './b';
+
+console.log(b);
@@ -0,0 +1,9 @@
+global.__modules.asyncModule("test.js", [
+    "./a",
+    "./b"
+], async ()=>{
+    const ___b = global.__modules.import("./b");
+    const b = ___b.b;
+    console.log(b);
+    global.__modules.reset("test.js");
+});
@@ -0,0 +1,5 @@
+import React from 'react';
+import * as config from './config';
+export { value } from './config';
+
+export const App = () => React.createElement('div', config);
@@ -0,0 +1,16 @@
+global.__modules.asyncModule("test.js", [
+  "react",
+  "./config"
+], async ()=>{
+  const ___config = global.__modules.import("./config");
+  const _react = global.__modules.import("react");
+  const React = _react.default;
+  const config = global.__modules.importWildcard("./config");
+  const __re_export = ___config.value;
+  const App = ()=>React.createElement('div', config);
+  global.__modules.init("test.js");
+  global.__modules.export("test.js", {
+    App,
+    value: __re_export
+  });
+});
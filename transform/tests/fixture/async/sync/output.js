@@ -0,0 +1,7 @@
+async function load() {
+  return await fetch('/config.json');
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  load
+});
@@ -0,0 +1,5 @@
+import { load } from './loader';
+
+const config = await load();
+
+export default config;
@@ -0,0 +1,12 @@
+global.__modules.asyncModule("test.js", [
+  "./loader"
+], async ()=>{
+  const ___loader = global.__modules.import("./loader");
+  const load = ___loader.load;
+  const config = await load();
+  const __export_default = config;
+  global.__modules.init("test.js");
+  global.__modules.export("test.js", {
+    default: __export_default
+  });
+});
@@ -0,0 +1,10 @@
+const value = 1;
+
+exports.value = value;
+exports['not-ident'] = 2;
+module.exports.named = function named() {};
+module.exports.self = module.exports;
+
+function bump(exports) {
+  exports.value = 2;
+}
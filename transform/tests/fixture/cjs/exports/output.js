@@ -0,0 +1,17 @@
+global.__modules.init("test.js", true);
+const value = 1;
+global.__modules.export("test.js", {
+  value: value
+});
+global.__modules.export("test.js", {
+  "not-ident": 2
+});
+global.__modules.export("test.js", {
+  named: function named() {}
+});
+global.__modules.export("test.js", {
+  self: global.__modules.import("test.js")
+});
+function bump(exports) {
+  exports.value = 2;
+}
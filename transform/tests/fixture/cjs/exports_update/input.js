@@ -0,0 +1,5 @@
+exports.count = 0;
+exports.count += 1;
+module.exports.count++;
+--exports['count'];
+exports.label ??= 'count';
@@ -0,0 +1,16 @@
+global.__modules.init("test.js", true);
+global.__modules.export("test.js", {
+    count: 0
+});
+global.__modules.export("test.js", {
+    count: global.__modules.import("test.js").count + 1
+});
+global.__modules.export("test.js", {
+    count: +global.__modules.import("test.js").count + 1
+});
+global.__modules.export("test.js", {
+    count: +global.__modules.import("test.js")['count'] - 1
+});
+global.__modules.export("test.js", {
+    label: global.__modules.import("test.js").label ?? 'count'
+});
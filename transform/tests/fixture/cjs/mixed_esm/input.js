@@ -0,0 +1,5 @@
+import { a } from 'mod_a';
+const b = require('mod_b');
+
+export const c = a + b;
+exports.d = c;
@@ -0,0 +1,11 @@
+global.__modules.init("test.js");
+const _mod_a = global.__modules.import("mod_a");
+const a = _mod_a.a;
+const b = global.__modules.import("mod_b");
+const c = a + b;
+global.__modules.export("test.js", {
+  d: c
+});
+global.__modules.export("test.js", {
+  c
+});
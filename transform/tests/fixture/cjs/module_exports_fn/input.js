@@ -0,0 +1,3 @@
+const App = module.exports = function App() {};
+
+module.exports.displayName = 'App';
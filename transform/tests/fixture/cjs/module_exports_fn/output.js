@@ -0,0 +1,5 @@
+global.__modules.init("test.js", true);
+const App = global.__modules.exportModule("test.js", function App() {});
+global.__modules.export("test.js", {
+    displayName: 'App'
+});
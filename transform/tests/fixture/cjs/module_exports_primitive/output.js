@@ -0,0 +1,2 @@
+global.__modules.init("test.js", true);
+global.__modules.exportModule("test.js", 42);
@@ -0,0 +1,11 @@
+const React = require('react');
+const { useState } = require('react');
+const utils = require(`./utils`);
+
+function load(require) {
+  return require('shadowed');
+}
+
+module.exports = function App() {
+  return React.createElement('div');
+};
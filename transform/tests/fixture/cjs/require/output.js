@@ -0,0 +1,10 @@
+global.__modules.init("test.js", true);
+const React = global.__modules.import("react");
+const { useState } = global.__modules.import("react");
+const utils = global.__modules.import("./utils");
+function load(require) {
+    return require('shadowed');
+}
+global.__modules.exportModule("test.js", function App() {
+    return React.createElement('div');
+});
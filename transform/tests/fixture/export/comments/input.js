@@ -0,0 +1,12 @@
+/**
+ * Returns the sum.
+ */
+export function sum(a, b) {
+  return a + b;
+}
+
+// eslint-disable-next-line no-var
+export var legacy = /* @__PURE__ */ createLegacy();
+
+/** Default component. */
+export default class {}
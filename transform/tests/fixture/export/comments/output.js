@@ -0,0 +1,15 @@
+/**
+ * Returns the sum.
+ */ function sum(a, b) {
+  return a + b;
+}
+// eslint-disable-next-line no-var
+var legacy = /* @__PURE__ */ createLegacy();
+/** Default component. */ class __Class {
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  sum,
+  legacy,
+  default: __Class
+});
@@ -0,0 +1,16 @@
+export let count = 0;
+export var flag = false, total = 0;
+export const LIMIT = 10;
+let renamed = null;
+
+export function increase() {
+  count++;
+  total += 1;
+}
+
+function reset({ value }) {
+  [flag] = [false];
+  renamed = value;
+}
+
+export { reset, renamed as current };
@@ -0,0 +1,32 @@
+let count = 0;
+var flag = false, total = 0;
+const LIMIT = 10;
+let renamed = null;
+function increase() {
+  count++;
+  total += 1;
+}
+function reset({ value }) {
+  [flag] = [
+    false
+  ];
+  renamed = value;
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  get count () {
+    return count;
+  },
+  get flag () {
+    return flag;
+  },
+  get total () {
+    return total;
+  },
+  LIMIT,
+  increase,
+  reset,
+  get current () {
+    return renamed;
+  }
+});
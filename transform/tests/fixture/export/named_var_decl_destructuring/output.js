@@ -0,0 +1,7 @@
+const a = 1, { b, c: [d] } = obj;
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  a,
+  b,
+  d
+});
@@ -0,0 +1,3 @@
+export const value = 1;
+export * from 'module_a';
+export * from 'module_b';
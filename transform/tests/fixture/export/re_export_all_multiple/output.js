@@ -0,0 +1,11 @@
+const __re_export_all = global.__modules.importWildcard("module_a");
+const __re_export_all1 = global.__modules.importWildcard("module_b");
+const value = 1;
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  value
+});
+global.__modules.exportAll("test.js", [
+  __re_export_all,
+  __re_export_all1
+]);
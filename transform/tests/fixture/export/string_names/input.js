@@ -0,0 +1,6 @@
+const a = 1;
+let b = 2;
+b++;
+export { a as 'a-b', b as 'b c' };
+export { 'x-y' as xy, z as 'z-z' } from 'mod';
+export * as 'ns-a' from 'mod_a';
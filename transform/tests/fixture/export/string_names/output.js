@@ -0,0 +1,17 @@
+const _mod = global.__modules.import("mod");
+const __re_export = _mod["x-y"];
+const __re_export1 = _mod.z;
+const __re_export2 = global.__modules.importWildcard("mod_a");
+const a = 1;
+let b = 2;
+b++;
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+    "a-b": a,
+    get "b c" () {
+        return b;
+    },
+    xy: __re_export,
+    "z-z": __re_export1,
+    "ns-a": __re_export2
+});
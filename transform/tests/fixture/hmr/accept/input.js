@@ -0,0 +1,10 @@
+import { render } from './render';
+
+export const count = import.meta.hot.data.count ?? 0;
+
+render(count);
+
+import.meta.hot.dispose((data) => {
+  data.count = count + 1;
+});
+import.meta.hot.accept();
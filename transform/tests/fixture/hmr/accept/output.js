@@ -0,0 +1,13 @@
+const ___render = app.__app_modules.import("./render");
+const render = ___render.render;
+const __hot = app.__app_modules.hot("test.js");
+const count = __hot.data.count ?? 0;
+render(count);
+__hot.dispose((data)=>{
+    data.count = count + 1;
+});
+__hot.accept();
+app.__app_modules.init("test.js");
+app.__app_modules.export("test.js", {
+    count
+});
@@ -0,0 +1,7 @@
+'use strict';
+import { log } from './log';
+
+if (import.meta.hot) {
+  import.meta.hot.accept((module) => log(module));
+}
+const url = import.meta.url;
@@ -0,0 +1,9 @@
+'use strict';
+const ___log = app.__app_modules.import("./log");
+const log = ___log.log;
+const __hot = app.__app_modules.hot("test.js");
+if (__hot) {
+    __hot.accept((module)=>log(module));
+}
+const url = import.meta.url;
+app.__app_modules.reset("test.js");
@@ -0,0 +1,3 @@
+import { value } from './value';
+
+export default import.meta.url + value;
@@ -0,0 +1,7 @@
+const ___value = app.__app_modules.import("./value");
+const value = ___value.value;
+const __export_default = import.meta.url + value;
+app.__app_modules.init("test.js");
+app.__app_modules.export("test.js", {
+    default: __export_default
+});
@@ -0,0 +1,4 @@
+import config from './config.json' with { type: 'json' };
+import { version } from './config.json' with { type: 'json' };
+import * as styles from './app.css' with { type: 'css' };
+import { load } from './config.json';
@@ -0,0 +1,15 @@
+const ___config_json = global.__modules.import("./config.json");
+const ___config_json1 = global.__modules.import("./config.json", {
+  with: {
+    type: 'json'
+  }
+});
+const config = ___config_json1.default;
+const version = ___config_json1.version;
+const styles = global.__modules.importWildcard("./app.css", {
+  with: {
+    type: 'css'
+  }
+});
+const load = ___config_json.load;
+global.__modules.reset("test.js");
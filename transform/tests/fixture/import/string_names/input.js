@@ -0,0 +1,2 @@
+import { 'a-b' as ab, 'default' as def } from 'mod';
+console.log(ab, def);
@@ -0,0 +1,5 @@
+const _mod = global.__modules.import("mod");
+const ab = _mod["a-b"];
+const def = _mod.default;
+console.log(ab, def);
+global.__modules.reset("test.js");
@@ -0,0 +1,4 @@
+import React, { useState } from 'react';
+import * as app from '@app/core';
+export { default as Button } from '@app/components';
+export * from '@app/module_a';
@@ -0,0 +1,14 @@
+const __app_components = global.__modules.import("@app/components");
+const _react = global.__modules.import("react");
+const React = global.__modules.interopDefault(_react).default;
+const useState = _react.useState;
+const app = global.__modules.interopWildcard(global.__modules.import("@app/core"));
+const __re_export = global.__modules.interopDefault(__app_components).default;
+const __re_export_all = global.__modules.interopWildcard(global.__modules.import("@app/module_a"));
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  Button: __re_export
+});
+global.__modules.exportAll("test.js", [
+  __re_export_all
+]);
@@ -0,0 +1,13 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+global.__modules.asyncModule("test.js", [
+  "./loader"
+], async ()=>{
+  'use strict';
+  const ___loader = global.__modules.import("./loader");
+  const load = ___loader.load;
+  const element = _jsx("div", await load());
+  global.__modules.init("test.js");
+  global.__modules.export("test.js", {
+    element
+  });
+});
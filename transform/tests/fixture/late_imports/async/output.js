@@ -0,0 +1,14 @@
+global.__modules.asyncModule("test.js", [
+    "./loader"
+], async ()=>{
+    'use strict';
+    const _react_jsx_runtime = global.__modules.import("react/jsx-runtime");
+    const _jsx = _react_jsx_runtime.jsx;
+    const ___loader = global.__modules.import("./loader");
+    const load = ___loader.load;
+    const element = _jsx("div", await load());
+    global.__modules.init("test.js");
+    global.__modules.export("test.js", {
+        element
+    });
+});
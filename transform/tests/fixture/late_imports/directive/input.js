@@ -0,0 +1,7 @@
+'use strict';
+import { _ as _object_spread } from "@swc/helpers/_/_object_spread";
+const props = _object_spread({}, defaults);
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  props
+});
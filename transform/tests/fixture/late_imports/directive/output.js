@@ -0,0 +1,8 @@
+'use strict';
+const __swc_helpers____object_spread = global.__modules.import("@swc/helpers/_/_object_spread");
+const _object_spread = __swc_helpers____object_spread._;
+const props = _object_spread({}, defaults);
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+    props
+});
@@ -0,0 +1,11 @@
+import { _ as _object_spread } from "@swc/helpers/_/_object_spread";
+import { jsx as _jsx } from "react/jsx-runtime";
+const _app_components = global.__modules.import("@app/components");
+const Container = _app_components.Container;
+function App(props) {
+  return _jsx(Container, _object_spread({}, props));
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  App
+});
@@ -0,0 +1,9 @@
+import { _ as _object_spread } from "@swc/helpers/_/_object_spread";
+/* @global-esm-keep */ import { jsx as _jsx } from "react/jsx-runtime";
+function App(props) {
+  return _jsx("div", _object_spread({}, props));
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  App
+});
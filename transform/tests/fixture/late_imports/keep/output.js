@@ -0,0 +1,10 @@
+const __swc_helpers____object_spread = global.__modules.import("@swc/helpers/_/_object_spread");
+const _object_spread = __swc_helpers____object_spread._;
+/* @global-esm-keep */ import { jsx as _jsx } from "react/jsx-runtime";
+function App(props) {
+    return _jsx("div", _object_spread({}, props));
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+    App
+});
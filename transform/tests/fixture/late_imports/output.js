@@ -0,0 +1,13 @@
+const __swc_helpers____object_spread = global.__modules.import("@swc/helpers/_/_object_spread");
+const _react_jsx_runtime = global.__modules.import("react/jsx-runtime");
+const _object_spread = __swc_helpers____object_spread._;
+const _jsx = _react_jsx_runtime.jsx;
+const _app_components = global.__modules.import("@app/components");
+const Container = _app_components.Container;
+function App(props) {
+  return _jsx(Container, _object_spread({}, props));
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  App
+});
@@ -0,0 +1,11 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+(()=>{
+  'use strict';
+  function App(props) {
+    return _jsx("div", props);
+  }
+  global.__modules.init("test.js");
+  global.__modules.export("test.js", {
+    App
+  });
+})();
@@ -0,0 +1,12 @@
+(()=>{
+    'use strict';
+    const _react_jsx_runtime = global.__modules.import("react/jsx-runtime");
+    const _jsx = _react_jsx_runtime.jsx;
+    function App(props) {
+        return _jsx("div", props);
+    }
+    global.__modules.init("test.js");
+    global.__modules.export("test.js", {
+        App
+    });
+})();
@@ -0,0 +1,9 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+{
+  const props = await load();
+  const element = _jsx("div", props);
+  global.__modules.init("test.js");
+  global.__modules.export("test.js", {
+    element
+  });
+}
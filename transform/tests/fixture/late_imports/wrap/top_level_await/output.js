@@ -0,0 +1,10 @@
+{
+    const _react_jsx_runtime = global.__modules.import("react/jsx-runtime");
+    const _jsx = _react_jsx_runtime.jsx;
+    const props = await load();
+    const element = _jsx("div", props);
+    global.__modules.init("test.js");
+    global.__modules.export("test.js", {
+        element
+    });
+}
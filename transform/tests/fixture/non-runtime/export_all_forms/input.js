@@ -0,0 +1,11 @@
+import { helper } from './helper';
+
+export const VERSION = '1.0.0';
+export function run() {
+  return helper();
+}
+export { helper };
+export { parse as parseConfig } from './config';
+export * as utils from './utils';
+export * from './shared';
+export default { run };
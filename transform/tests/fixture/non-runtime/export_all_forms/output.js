@@ -0,0 +1,28 @@
+import { parse as __re_export } from "./config";
+import * as __re_export1 from "./utils";
+import * as __re_export_all from "./shared";
+import { helper } from './helper';
+export const VERSION = '1.0.0';
+export function run() {
+  return helper();
+}
+export { helper };
+export { parse as parseConfig } from './config';
+export * as utils from './utils';
+export * from './shared';
+const __export_default = {
+  run
+};
+export default __export_default;
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  VERSION,
+  run,
+  helper,
+  default: __export_default,
+  parseConfig: __re_export,
+  utils: __re_export1
+});
+global.__modules.exportAll("test.js", [
+  __re_export_all
+]);
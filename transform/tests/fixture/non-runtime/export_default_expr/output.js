@@ -1,4 +1,5 @@
 const __export_default = 0;
+export default __export_default;
 global.__modules.init("test.js");
 global.__modules.export("test.js", {
   default: __export_default
@@ -0,0 +1,5 @@
+export default function main() {}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  default: main
+});
@@ -0,0 +1,7 @@
+const value = 1;
+const __export_default = value;
+export default __export_default;
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  default: __export_default
+});
@@ -0,0 +1,6 @@
+const value = 1;
+export { value as default };
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  default: value
+});
@@ -0,0 +1,6 @@
+export class Service {
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  Service
+});
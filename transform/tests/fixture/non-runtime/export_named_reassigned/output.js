@@ -0,0 +1,33 @@
+export let count = 0;
+export var flag = false, total = 0;
+export const LIMIT = 10;
+let renamed = null;
+export function increase() {
+  count++;
+  total += 1;
+}
+function reset({ value }) {
+  [flag] = [
+    false
+  ];
+  renamed = value;
+}
+export { reset, renamed as current };
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  get count () {
+    return count;
+  },
+  get flag () {
+    return flag;
+  },
+  get total () {
+    return total;
+  },
+  LIMIT,
+  increase,
+  reset,
+  get current () {
+    return renamed;
+  }
+});
@@ -1,6 +1,6 @@
 import * as __re_export_all from "module";
 export * from 'module';
 global.__modules.init("test.js");
-global.__modules.exportAll("test.js", {
-  ...__re_export_all
-});
+global.__modules.exportAll("test.js", [
+  __re_export_all
+]);
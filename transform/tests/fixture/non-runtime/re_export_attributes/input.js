@@ -0,0 +1,2 @@
+export { default as config } from './config.json' with { type: 'json' };
+export * from './app.css' with { type: 'css' };
@@ -0,0 +1,19 @@
+import __re_export from "./config.json" with {
+  type: 'json'
+};
+import * as __re_export_all from "./app.css" with {
+  type: 'css'
+};
+export { default as config } from './config.json' with {
+  type: 'json'
+};
+export * from './app.css' with {
+  type: 'css'
+};
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  config: __re_export
+});
+global.__modules.exportAll("test.js", [
+  __re_export_all
+]);
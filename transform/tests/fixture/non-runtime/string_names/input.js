@@ -0,0 +1,2 @@
+import { 'a-b' as ab } from 'mod';
+export { ab as 'a-b' };
@@ -0,0 +1,6 @@
+import { 'a-b' as ab } from 'mod';
+export { ab as 'a-b' };
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+    "a-b": ab
+});
@@ -0,0 +1,5 @@
+// @global-esm-ignore
+import 'core-js/stable';
+import { polyfill } from './polyfill';
+
+export const applied = polyfill();
@@ -0,0 +1,7 @@
+import React from 'react';
+/* @global-esm-keep */
+import { worker } from 'native:worker';
+
+/* @global-esm-keep */
+export const native = worker;
+export const App = React;
@@ -0,0 +1,9 @@
+const _react = global.__modules.import("react");
+const React = _react.default;
+/* @global-esm-keep */ import { worker } from 'native:worker';
+/* @global-esm-keep */ export const native = worker;
+const App = React;
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  App
+});
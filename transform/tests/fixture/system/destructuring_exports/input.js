@@ -0,0 +1,12 @@
+export let a = 0;
+export let b = 0;
+let c;
+
+[a, { b }] = [1, { b: 2 }];
+({ a, b: c } = { a: 3, b: 4 });
+c = [b] = [5];
+
+for (a of [6, 7]) console.log(a);
+for (b in { key: 8 }) {
+  console.log(b);
+}
@@ -0,0 +1,41 @@
+System.register([], function(_export, _context) {
+    "use strict";
+    var a, b, c, _ref;
+    return {
+        setters: [],
+        execute: function() {
+            a = 0;
+            b = 0;
+            [a, { b }] = [
+                1,
+                {
+                    b: 2
+                }
+            ], _export("a", a), _export("b", b);
+            ({ a, b: c } = {
+                a: 3,
+                b: 4
+            }), _export("a", a);
+            c = (_ref = [b] = [
+                5
+            ], _export("b", b), _ref);
+            for (a of [
+                6,
+                7
+            ]){
+                _export("a", a);
+                console.log(a);
+            }
+            for(b in {
+                key: 8
+            }){
+                _export("b", b);
+                console.log(b);
+            }
+            _export({
+                a: a,
+                b: b
+            });
+        }
+    };
+});
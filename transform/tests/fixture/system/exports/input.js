@@ -0,0 +1,9 @@
+export let count = 0;
+export const LIMIT = 10;
+
+export function increase() {
+  count++;
+  count += 1;
+}
+
+export default await Promise.resolve(LIMIT);
@@ -0,0 +1,22 @@
+System.register([], function(_export, _context) {
+    "use strict";
+    var count, LIMIT, __export_default;
+    function increase() {
+        _export("count", +count + 1), count++;
+        _export("count", count += 1);
+    }
+    _export("increase", increase);
+    return {
+        setters: [],
+        execute: async function() {
+            count = 0;
+            LIMIT = 10;
+            __export_default = await Promise.resolve(LIMIT);
+            _export({
+                count: count,
+                LIMIT: LIMIT,
+                default: __export_default
+            });
+        }
+    };
+});
@@ -0,0 +1,11 @@
+import { log } from './cycle';
+
+const prefix = '[app]';
+
+export function print(message) {
+  log(prefix + message);
+}
+
+export class Logger {}
+
+export default function () {}
@@ -0,0 +1,27 @@
+System.register([
+    "./cycle"
+], function(_export, _context) {
+    "use strict";
+    var log, prefix, Logger;
+    function print(message) {
+        log(prefix + message);
+    }
+    function __fn() {}
+    _export("print", print);
+    _export("default", __fn);
+    return {
+        setters: [
+            function(___cycle) {
+                log = ___cycle.log;
+            }
+        ],
+        execute: function() {
+            prefix = '[app]';
+            Logger = class Logger {
+            };
+            _export({
+                Logger: Logger
+            });
+        }
+    };
+});
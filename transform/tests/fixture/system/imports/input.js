@@ -0,0 +1,8 @@
+import React, { useState, useEffect as effect } from 'react';
+import * as app from '@app/core';
+
+export function App() {
+  useState(app);
+  effect();
+  return React.createElement('div');
+}
@@ -0,0 +1,26 @@
+System.register([
+    "react",
+    "@app/core"
+], function(_export, _context) {
+    "use strict";
+    var React, useState, effect, app;
+    function App() {
+        useState(app);
+        effect();
+        return React.createElement('div');
+    }
+    _export("App", App);
+    return {
+        setters: [
+            function(_react) {
+                React = _react.default;
+                useState = _react.useState;
+                effect = _react.useEffect;
+            },
+            function(__app_core) {
+                app = __app_core;
+            }
+        ],
+        execute: function() {}
+    };
+});
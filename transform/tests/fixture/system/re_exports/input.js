@@ -0,0 +1,5 @@
+import { a } from 'mod_a';
+export { a };
+export { b as c, default as d } from 'mod_b';
+export * as ns from 'mod_c';
+export * from 'mod_d';
@@ -0,0 +1,46 @@
+System.register([
+    "mod_a",
+    "mod_b",
+    "mod_c",
+    "mod_d"
+], function(_export, _context) {
+    "use strict";
+    var a, __re_export, __re_export1, __re_export2, __re_export_all;
+    var _exportNames = {
+        a: true,
+        c: true,
+        d: true,
+        ns: true
+    };
+    function _exportStar() {
+        var _namespaces = [
+            __re_export_all
+        ];
+        if (_namespaces.every(Boolean)) _namespaces.forEach(function(_ns) {
+            for(var _key in _ns)if (_key !== "default" && _exportNames[_key] !== true) _export(_key, _ns[_key]);
+        });
+    }
+    return {
+        setters: [
+            function(_mod_a) {
+                a = _mod_a.a;
+                _export("a", a);
+            },
+            function(_mod_b) {
+                __re_export = _mod_b.b;
+                _export("c", __re_export);
+                __re_export1 = _mod_b.default;
+                _export("d", __re_export1);
+            },
+            function(_mod_c) {
+                __re_export2 = _mod_c;
+                _export("ns", __re_export2);
+            },
+            function(_mod_d) {
+                __re_export_all = _mod_d;
+                _exportStar();
+            }
+        ],
+        execute: function() {}
+    };
+});
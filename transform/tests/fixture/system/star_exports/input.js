@@ -0,0 +1,4 @@
+export const a = 1;
+export { b } from 'mod_b';
+export * from 'mod_c';
+export * from 'mod_d';
@@ -0,0 +1,45 @@
+System.register([
+    "mod_b",
+    "mod_c",
+    "mod_d"
+], function(_export, _context) {
+    "use strict";
+    var __re_export, __re_export_all, __re_export_all1, a;
+    var _exportNames = {
+        a: true,
+        b: true
+    };
+    function _exportStar() {
+        var _namespaces = [
+            __re_export_all,
+            __re_export_all1
+        ];
+        if (_namespaces.every(Boolean)) _namespaces.forEach(function(_ns) {
+            for(var _key in _ns)if (_key !== "default" && _exportNames[_key] !== true && _namespaces.every(function(_other) {
+                return !(_key in _other) || _other[_key] === _ns[_key];
+            })) _export(_key, _ns[_key]);
+        });
+    }
+    return {
+        setters: [
+            function(_mod_b) {
+                __re_export = _mod_b.b;
+                _export("b", __re_export);
+            },
+            function(_mod_c) {
+                __re_export_all = _mod_c;
+                _exportStar();
+            },
+            function(_mod_d) {
+                __re_export_all1 = _mod_d;
+                _exportStar();
+            }
+        ],
+        execute: function() {
+            a = 1;
+            _export({
+                a: a
+            });
+        }
+    };
+});
@@ -0,0 +1,7 @@
+export let count = '1';
+export let total = 0;
+
+count++;
+total--;
+++count;
+--total;
@@ -0,0 +1,19 @@
+System.register([], function(_export, _context) {
+    "use strict";
+    var count, total;
+    return {
+        setters: [],
+        execute: function() {
+            count = '1';
+            total = 0;
+            _export("count", +count + 1), count++;
+            _export("total", +total - 1), total--;
+            _export("count", ++count);
+            _export("total", --total);
+            _export({
+                count: count,
+                total: total
+            });
+        }
+    };
+});
@@ -0,0 +1,12 @@
+import React, { useState } from 'react';
+import type { ReactNode } from 'react';
+import { Button } from './button';
+import { Icon } from './icon';
+
+export function App({ children }: { children: ReactNode }) {
+  return (
+    <>
+      <Button>{children}</Button>
+    </>
+  );
+}
@@ -0,0 +1,17 @@
+const ___button = global.__modules.import("./button");
+const _react = global.__modules.import("react");
+const React = _react.default;
+const Button = ___button.Button;
+function App({ children }: {
+    children: ReactNode;
+}) {
+    return <>
+
+      <Button>{children}</Button>
+
+    </>;
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+    App
+});
@@ -0,0 +1,15 @@
+import React, { useState, useEffect } from 'react';
+import { Props } from './types';
+import * as utils from './utils';
+import { unused } from './unused';
+import { format } from './format';
+import { foo } from './foo';
+
+export { foo };
+
+export function App(props: Props) {
+  const [state] = useState(0);
+  return utils.format(state, { format });
+}
+
+export * from './components';
@@ -0,0 +1,22 @@
+const ___foo = global.__modules.import("./foo");
+const ___format = global.__modules.import("./format");
+const _react = global.__modules.import("react");
+const useState = _react.useState;
+const utils = global.__modules.importWildcard("./utils");
+const format = ___format.format;
+const foo = ___foo.foo;
+const __re_export_all = global.__modules.importWildcard("./components");
+function App(props: Props) {
+  const [state] = useState(0);
+  return utils.format(state, {
+    format
+  });
+}
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  foo,
+  App
+});
+global.__modules.exportAll("test.js", [
+  __re_export_all
+]);
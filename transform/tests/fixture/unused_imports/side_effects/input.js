@@ -0,0 +1,5 @@
+import { setup } from './polyfill';
+import { theme } from './theme';
+import { used, unused } from './side-effect';
+
+export const value = used;
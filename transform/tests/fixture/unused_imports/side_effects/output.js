@@ -0,0 +1,8 @@
+global.__modules.import("./polyfill");
+const ___side_effect = global.__modules.import("./side-effect");
+const used = ___side_effect.used;
+const value = used;
+global.__modules.init("test.js");
+global.__modules.export("test.js", {
+  value
+});
@@ -0,0 +1,6 @@
+import './polyfill';
+import './styles.css';
+import { used } from './side-effect';
+import './side-effect';
+
+export const value = used;
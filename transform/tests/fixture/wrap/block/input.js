@@ -0,0 +1,8 @@
+'use strict';
+import React from 'react';
+
+export const element = render();
+
+function render() {
+  return React.createElement('div');
+}
@@ -0,0 +1,13 @@
+'use strict';
+{
+  const _react = global.__modules.import("react");
+  const React = _react.default;
+  const element = render();
+  function render() {
+    return React.createElement('div');
+  }
+  global.__modules.init("test.js");
+  global.__modules.export("test.js", {
+    element
+  });
+}
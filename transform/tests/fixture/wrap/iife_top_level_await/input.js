@@ -0,0 +1,3 @@
+import { load } from '@app/loader';
+
+export const data = await load();
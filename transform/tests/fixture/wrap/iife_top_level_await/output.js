@@ -0,0 +1,9 @@
+{
+  const __app_loader = global.__modules.import("@app/loader");
+  const load = __app_loader.load;
+  const data = await load();
+  global.__modules.init("test.js");
+  global.__modules.export("test.js", {
+    data
+  });
+}
@@ -0,0 +1,251 @@
+mod common;
+
+use std::process::Command;
+
+use common::parse;
+use swc_global_esm::{generate_runtime, GlobalEsmConfig, Interop};
+
+/// Run the script after the runtime with Node.js and returns the output lines.
+fn run(config: &GlobalEsmConfig, script: &str) -> Vec<String> {
+    let output = Command::new("node")
+        .arg("-e")
+        .arg(format!("{}\n{script}", generate_runtime(config)))
+        .output()
+        .expect("failed to run node");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn generate_default_runtime() {
+    let runtime = generate_runtime(&GlobalEsmConfig::default());
+    parse(&runtime);
+
+    assert!(runtime.contains("Object.defineProperty(globalObject, '__modules'"));
+    assert!(runtime.contains("if (!('global' in globalObject))"));
+    [
+        "reset(",
+        "init(",
+        "registerLoader(",
+        "import(",
+        "importWildcard(",
+        "export(",
+        "exportAll(",
+    ]
+    .iter()
+    .for_each(|method| assert!(runtime.contains(method), "{method} is missing"));
+    [
+        "asyncModule(",
+        "interopDefault(",
+        "interopWildcard(",
+        "hot(",
+    ]
+    .iter()
+    .for_each(|method| assert!(!runtime.contains(method), "{method} is included"));
+}
+
+#[test]
+fn generate_runtime_with_features() {
+    let runtime = generate_runtime(&GlobalEsmConfig {
+        async_mode: true,
+        interop: Interop::Babel,
+        hmr: true,
+        ..Default::default()
+    });
+    parse(&runtime);
+
+    [
+        "asyncModule(",
+        "interopDefault(",
+        "interopWildcard(",
+        "hot(",
+    ]
+    .iter()
+    .for_each(|method| assert!(runtime.contains(method), "{method} is missing"));
+    assert!(runtime.contains("asyncModules[moduleName] = undefined;"));
+}
+
+#[test]
+fn generate_runtime_with_names() {
+    let runtime = generate_runtime(&GlobalEsmConfig {
+        global_name: Some(String::from("app")),
+        registry_name: Some(String::from("__app_modules")),
+        ..Default::default()
+    });
+    parse(&runtime);
+
+    assert!(runtime.contains("Object.defineProperty(globalObject, '__app_modules'"));
+    assert!(runtime.contains("if (!('app' in globalObject))"));
+    assert!(!runtime.contains("'__modules'"));
+}
+
+#[test]
+fn run_export_and_import() {
+    let output = run(
+        &GlobalEsmConfig::default(),
+        r#"
+        const { __modules } = global;
+        __modules.init('a.js');
+        let count = 1;
+        __modules.export('a.js', { count: count, default: 'a' });
+        __modules.export('a.js', { get count() { return count; } });
+        count = 2;
+
+        const module = __modules.import('a.js');
+        console.log(module.__esModule, module.count, module.default);
+        console.log(JSON.stringify(__modules.importWildcard('a.js')));
+
+        __modules.reset('a.js');
+        try {
+          __modules.import('a.js');
+        } catch (error) {
+          console.log(error.message);
+        }
+        "#,
+    );
+
+    assert_eq!(
+        output,
+        vec![
+            "true 2 a",
+            r#"{"count":2}"#,
+            r#"[Global ESM] "a.js" module not found"#,
+        ]
+    );
+}
+
+#[test]
+fn run_export_all() {
+    let output = run(
+        &GlobalEsmConfig::default(),
+        r#"
+        const { __modules } = global;
+        __modules.init('a.js');
+        __modules.export('a.js', { local: 'local' });
+        __modules.exportAll('a.js', [
+          { a: 'a', ambiguous: 1, shared: 'shared', local: 'a' },
+          { b: 'b', ambiguous: 2, shared: 'shared', default: 'b' },
+        ]);
+        console.log(JSON.stringify(__modules.import('a.js')));
+        "#,
+    );
+
+    assert_eq!(
+        output,
+        vec![r#"{"local":"local","a":"a","shared":"shared","b":"b"}"#]
+    );
+}
+
+#[test]
+fn run_export_module() {
+    let output = run(
+        &GlobalEsmConfig {
+            interop: Interop::Babel,
+            ..Default::default()
+        },
+        r#"
+        const { __modules } = global;
+        __modules.init('fn.js', true);
+        const App = __modules.exportModule('fn.js', function App() {});
+        __modules.export('fn.js', { displayName: 'App' });
+        const fn = __modules.import('fn.js');
+        console.log(fn === App, fn.displayName, __modules.interopDefault(fn).default === App);
+
+        __modules.init('primitive.js', true);
+        __modules.exportModule('primitive.js', 0);
+        console.log(__modules.import('primitive.js'), __modules.interopDefault(0).default);
+        "#,
+    );
+
+    assert_eq!(output, vec!["true App true", "0 0"]);
+}
+
+#[test]
+fn run_async_module() {
+    let output = run(
+        &GlobalEsmConfig {
+            async_mode: true,
+            ..Default::default()
+        },
+        r#"
+        const { __modules } = global;
+        __modules.asyncModule('a.js', [], async () => {
+          await null;
+          __modules.init('a.js');
+          __modules.export('a.js', { value: 1 });
+          console.log('a');
+        });
+        __modules.asyncModule('b.js', ['a.js'], async () => {
+          console.log('b', __modules.import('a.js').value);
+        });
+        console.log('registered');
+        "#,
+    );
+
+    assert_eq!(output, vec!["registered", "a", "b 1"]);
+}
+
+#[test]
+fn run_runtime_with_names() {
+    let output = run(
+        &GlobalEsmConfig {
+            global_name: Some(String::from("app")),
+            registry_name: Some(String::from("__app_modules")),
+            ..Default::default()
+        },
+        r#"
+        app.__app_modules.init('a.js');
+        app.__app_modules.export('a.js', { value: 1 });
+        console.log(app.__app_modules.import('a.js').value, typeof globalThis.__modules);
+        "#,
+    );
+
+    assert_eq!(output, vec!["1 undefined"]);
+}
+
+#[test]
+fn run_hot_update() {
+    let output = run(
+        &GlobalEsmConfig {
+            hmr: true,
+            ..Default::default()
+        },
+        r#"
+        const { __modules } = global;
+        // Module body after the transform (`import.meta.hot` is `__hot`).
+        function evaluate(value) {
+          const __hot = __modules.hot('a.js');
+          console.log('evaluate', value, JSON.stringify(__hot.data));
+          __hot.dispose((data) => {
+            data.value = value;
+          });
+          __hot.accept((module) => console.log('accept', value, '->', module.value));
+          __modules.init('a.js');
+          __modules.export('a.js', { value });
+        }
+
+        evaluate(1);
+        evaluate(2);
+        console.log('evaluated');
+        "#,
+    );
+
+    assert_eq!(
+        output,
+        vec![
+            "evaluate 1 {}",
+            r#"evaluate 2 {"value":1}"#,
+            "evaluated",
+            "accept 1 -> 2",
+        ]
+    );
+}
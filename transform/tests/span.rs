@@ -0,0 +1,66 @@
+mod common;
+
+use common::parse;
+use swc_core::{
+    common::{comments::NoopComments, Globals, Span, Spanned, GLOBALS},
+    ecma::{ast::*, visit::VisitMutWith},
+};
+use swc_global_esm::{global_esm, GlobalEsmConfig};
+
+fn item_spans(module: &Module) -> Vec<Span> {
+    module.body.iter().map(|item| item.span()).collect()
+}
+
+#[test]
+fn generated_statements_keep_original_spans() {
+    let mut module = parse(
+        r#"import React, { useState } from 'react';
+import * as app from '@app/core';
+export default app;
+export { useState };
+export * from '@app/module_a';
+"#,
+    );
+    let original_spans = item_spans(&module);
+
+    GLOBALS.set(&Globals::new(), || {
+        module.visit_mut_with(&mut global_esm(
+            String::from("test.js"),
+            GlobalEsmConfig {
+                runtime_module: true,
+                ..Default::default()
+            },
+            NoopComments,
+        ));
+    });
+
+    let spans = item_spans(&module);
+    let [import_react, import_app, export_default, _, export_all] = original_spans[..] else {
+        panic!("unexpected module items");
+    };
+
+    assert_eq!(
+        spans,
+        vec![
+            // const _react = global.__modules.import("react");
+            import_react,
+            // const React = _react.default;
+            import_react,
+            // const useState = _react.useState;
+            import_react,
+            // const app = global.__modules.importWildcard("@app/core");
+            import_app,
+            // const __re_export_all = global.__modules.importWildcard("@app/module_a");
+            export_all,
+            // const __export_default = app;
+            export_default,
+            // global.__modules.init("test.js");
+            export_default,
+            // global.__modules.export("test.js", { ... });
+            export_default,
+            // global.__modules.exportAll("test.js", [ ... ]);
+            export_all,
+        ]
+    );
+    assert!(spans.iter().all(|span| !span.is_dummy()));
+}